@@ -0,0 +1,328 @@
+use std::collections::HashSet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{Card, Suit, Value, DECK_SIZE};
+
+const FULL_DECK_MASK: u64 = (1 << DECK_SIZE) - 1;
+
+/// Returns a 52-bit mask of the provided cards
+///
+/// Bit `card.to_int() - 1` is set for each card, identical to the mask of a `BitDeck`. Since
+/// duplicate cards set the same bit, comparing the number of set bits with the number of cards
+/// detects duplicates without allocating.
+///
+/// Example
+/// ```rust
+/// use playing_cards::core::{card_mask, Card};
+///
+/// let cards = Card::vec_from_str("AsKsAs").unwrap();
+///
+/// assert_eq!(card_mask(&cards[..2]).count_ones(), 2);
+/// assert_ne!(card_mask(&cards).count_ones() as usize, cards.len());
+/// ```
+pub fn card_mask(cards: &[Card]) -> u64 {
+    cards
+        .iter()
+        .fold(0, |acc, card| acc | BitDeck::card_bit(card))
+}
+
+/// A standard 52-card deck represented as a bitmask
+///
+/// Each card is represented by a single bit, where bit `card.to_int() - 1` is set if the card is
+/// present in the deck. Unlike `CardDeck`, a `BitDeck` has no notion of card order, but
+/// stripping, membership checks, and counting cards are all constant-time operations. This makes
+/// it a good fit for tracking which cards remain when enumerating boards or hands.
+///
+/// With the `serde` feature enabled, this struct also implements serde's `Serialize` and
+/// `Deserialize` traits. A BitDeck is serialized as its mask, and deserializing a mask with any
+/// bit above the 52 cards of the deck set will fail.
+///
+/// Example
+/// ```rust
+/// use std::collections::HashSet;
+/// use playing_cards::core::{BitDeck, Card, Value};
+///
+/// let mut deck = BitDeck::new();
+///
+/// let removed = deck.strip_ranks(&HashSet::from([Value::Ace]));
+///
+/// assert_eq!(removed.count(), 4);
+/// assert_eq!(deck.count(), 48);
+/// assert!(!deck.contains(&Card::vec_from_str("As").unwrap()[0]));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "u64"),
+    serde(into = "u64")
+)]
+pub struct BitDeck {
+    mask: u64,
+}
+
+impl TryFrom<u64> for BitDeck {
+    type Error = String;
+    fn try_from(mask: u64) -> Result<Self, Self::Error> {
+        if mask & !FULL_DECK_MASK != 0 {
+            return Err(format!(
+                "Bit deck mask {:#x} has bits set outside of the {} cards of the deck",
+                mask, DECK_SIZE
+            ));
+        }
+        Ok(Self { mask })
+    }
+}
+
+impl From<BitDeck> for u64 {
+    fn from(deck: BitDeck) -> Self {
+        deck.mask
+    }
+}
+
+impl Default for BitDeck {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BitDeck {
+    /// Creates a new BitDeck containing all 52 cards
+    pub fn new() -> Self {
+        Self {
+            mask: FULL_DECK_MASK,
+        }
+    }
+
+    /// Creates a new BitDeck containing no cards
+    pub fn empty() -> Self {
+        Self { mask: 0 }
+    }
+
+    /// Creates a new BitDeck containing only the provided cards
+    ///
+    /// Duplicate cards are only counted once.
+    pub fn from_cards(cards: &[Card]) -> Self {
+        Self {
+            mask: card_mask(cards),
+        }
+    }
+
+    fn card_bit(card: &Card) -> u64 {
+        1 << (card.to_int() - 1)
+    }
+
+    /// Returns the underlying bitmask of the deck
+    pub fn mask(&self) -> u64 {
+        self.mask
+    }
+
+    /// Returns true if the card is in the deck
+    pub fn contains(&self, card: &Card) -> bool {
+        self.mask & Self::card_bit(card) != 0
+    }
+
+    /// Returns the number of cards in the deck
+    pub fn count(&self) -> usize {
+        self.mask.count_ones() as usize
+    }
+
+    /// Returns true if there are no cards in the deck
+    pub fn is_empty(&self) -> bool {
+        self.mask == 0
+    }
+
+    /// Adds the provided cards into the deck
+    pub fn insert(&mut self, cards: &[Card]) {
+        self.mask |= Self::from_cards(cards).mask;
+    }
+
+    /// Removes all cards within the provided deck from this deck
+    ///
+    /// Returns back a BitDeck of the cards that were removed from the deck.
+    pub fn strip(&mut self, cards_to_remove: &BitDeck) -> BitDeck {
+        let removed = self.mask & cards_to_remove.mask;
+        self.mask &= !removed;
+        BitDeck { mask: removed }
+    }
+
+    /// Removes all cards with the provided ranks/values from the deck
+    ///
+    /// Returns back a BitDeck of the cards that were removed from the deck.
+    pub fn strip_ranks(&mut self, ranks_to_remove: &HashSet<Value>) -> BitDeck {
+        let mask = ranks_to_remove
+            .iter()
+            .fold(0, |acc, &value| acc | (0xf << (value as u64 * 4)));
+        self.strip(&BitDeck { mask })
+    }
+
+    /// Removes all cards with the provided suits from the deck
+    ///
+    /// Returns back a BitDeck of the cards that were removed from the deck.
+    pub fn strip_suits(&mut self, suits_to_remove: &HashSet<Suit>) -> BitDeck {
+        // every 4th bit starting from the first bit
+        const SUIT_MASK: u64 = 0x1_1111_1111_1111;
+        let mask = suits_to_remove
+            .iter()
+            .fold(0, |acc, &suit| acc | (SUIT_MASK << suit as u64));
+        self.strip(&BitDeck { mask })
+    }
+
+    /// Deals the card with the lowest bit from the deck
+    ///
+    /// Returns None if there are no cards left in the deck.
+    pub fn deal(&mut self) -> Option<Card> {
+        if self.mask == 0 {
+            return None;
+        }
+
+        let bit = self.mask.trailing_zeros();
+        self.mask &= self.mask - 1;
+        Some(Card::from(bit as i32 + 1))
+    }
+
+    /// Returns all cards in the deck sorted from the lowest bit to the highest bit
+    pub fn cards(&self) -> Vec<Card> {
+        let mut deck = *self;
+        std::iter::from_fn(|| deck.deal()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::CardDeck;
+    use super::*;
+
+    fn as_set(cards: Vec<Card>) -> HashSet<Card> {
+        cards.into_iter().collect()
+    }
+
+    #[test]
+    fn full_deck_matches_card_deck() {
+        let deck = BitDeck::new();
+        let card_deck = CardDeck::default();
+
+        assert_eq!(deck.count(), DECK_SIZE);
+        assert_eq!(as_set(deck.cards()), as_set(card_deck.collect()));
+    }
+
+    #[test]
+    fn strip_cards_matches_card_deck() {
+        let cards = Card::vec_from_str("5d2h8d3h").expect("Failed parsing card string");
+        let mut deck = BitDeck::new();
+        let mut card_deck = CardDeck::default();
+
+        let removed = deck.strip(&BitDeck::from_cards(&cards));
+        let card_deck_removed = card_deck.strip_cards(&as_set(cards.clone()));
+
+        assert_eq!(as_set(removed.cards()), as_set(card_deck_removed));
+        assert_eq!(deck.count(), card_deck.len());
+        for card in &cards {
+            assert!(!deck.contains(card));
+        }
+        assert_eq!(as_set(deck.cards()), as_set(card_deck.collect()));
+    }
+
+    #[test]
+    fn strip_ranks_matches_card_deck() {
+        let ranks = HashSet::from([Value::Ace, Value::Two, Value::Three]);
+        let mut deck = BitDeck::new();
+        let mut card_deck = CardDeck::default();
+
+        let removed = deck.strip_ranks(&ranks);
+        let card_deck_removed = card_deck.strip_ranks(&ranks);
+
+        assert_eq!(removed.count(), 12);
+        assert_eq!(as_set(removed.cards()), as_set(card_deck_removed));
+        assert_eq!(as_set(deck.cards()), as_set(card_deck.collect()));
+    }
+
+    #[test]
+    fn strip_suits_matches_card_deck() {
+        let suits = HashSet::from([Suit::Spade, Suit::Diamond]);
+        let mut deck = BitDeck::new();
+        let mut card_deck = CardDeck::default();
+
+        let removed = deck.strip_suits(&suits);
+        let card_deck_removed = card_deck.strip_suits(&suits);
+
+        assert_eq!(removed.count(), 26);
+        assert_eq!(as_set(removed.cards()), as_set(card_deck_removed));
+        assert_eq!(as_set(deck.cards()), as_set(card_deck.collect()));
+    }
+
+    #[test]
+    fn card_mask_detects_duplicates() {
+        let cards = Card::vec_from_str("As2h5dAs").unwrap();
+
+        assert_eq!(card_mask(&cards[..3]).count_ones(), 3);
+        assert_eq!(card_mask(&cards).count_ones(), 3);
+        assert_eq!(card_mask(&cards), BitDeck::from_cards(&cards).mask());
+        assert_eq!(card_mask(&[]), 0);
+    }
+
+    #[test]
+    fn deal_lowest_card() {
+        let mut deck = BitDeck::from_cards(&Card::vec_from_str("As2h5d").unwrap());
+
+        assert_eq!(deck.deal(), Some(Card::from(1))); // 2h
+        assert_eq!(deck.deal(), Some(Card::from(15))); // 5d
+        assert_eq!(deck.deal(), Some(Card::from(52))); // As
+        assert_eq!(deck.deal(), None);
+        assert!(deck.is_empty());
+    }
+
+    #[test]
+    fn mask_conversion() {
+        let deck = BitDeck::from_cards(&Card::vec_from_str("2hAs").unwrap());
+
+        assert_eq!(BitDeck::try_from(u64::from(deck)), Ok(deck));
+        assert_eq!(BitDeck::try_from(FULL_DECK_MASK), Ok(BitDeck::new()));
+        assert_eq!(
+            BitDeck::try_from(1 << DECK_SIZE),
+            Err(
+                "Bit deck mask 0x10000000000000 has bits set outside of the 52 cards of the deck"
+                    .to_string()
+            )
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let deck = BitDeck::from_cards(&Card::vec_from_str("2hAsTd").unwrap());
+
+        let json = serde_json::to_string(&deck).expect("Deck could not be serialized");
+        let round_trip: BitDeck =
+            serde_json::from_str(&json).expect("Deck could not be deserialized");
+
+        assert_eq!(json, deck.mask().to_string());
+        assert_eq!(round_trip, deck);
+        assert!(serde_json::from_str::<BitDeck>(&(1u64 << DECK_SIZE).to_string()).is_err());
+        assert!(serde_json::from_str::<BitDeck>(&u64::MAX.to_string()).is_err());
+    }
+}
+
+#[cfg(all(feature = "unstable", test))]
+mod bench {
+    use super::*;
+    use test::{black_box, Bencher};
+
+    #[bench]
+    fn bench_card_mask_duplicate_check(b: &mut Bencher) {
+        let cards = Card::vec_from_str("2h5dAsAdKdJc3h").unwrap();
+
+        b.iter(|| black_box(card_mask(black_box(&cards)).count_ones() as usize == cards.len()))
+    }
+
+    #[bench]
+    fn bench_hashset_duplicate_check(b: &mut Bencher) {
+        let cards = Card::vec_from_str("2h5dAsAdKdJc3h").unwrap();
+
+        b.iter(|| {
+            black_box(HashSet::<&Card>::from_iter(black_box(&cards).iter()).len() == cards.len())
+        })
+    }
+}
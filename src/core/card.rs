@@ -1,21 +1,42 @@
 use num_traits::FromPrimitive;
+use std::cmp::Ordering;
 use std::str::FromStr;
+use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
+use thiserror::Error;
+
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// The number of distinct values (ranks) in a standard deck
+pub const NUM_VALUES: usize = 13;
+
+/// The number of distinct suits in a standard deck
+pub const NUM_SUITS: usize = 4;
+
+/// The number of cards in a standard deck
+pub const DECK_SIZE: usize = NUM_VALUES * NUM_SUITS;
+
 /// An enum representation of the rank of a card
 ///
 /// Each value corresponds to the rank strength.
 ///
 /// With the `serde` feature enabled, this struct also implements serde's `Serialize` and
-/// `Deserialize` traits.
+/// `Deserialize` traits. A Value is serialized as its character string (e.g. "A" for an Ace, "T"
+/// for a 10).
 #[allow(missing_docs)]
 #[derive(
     Debug, Clone, Copy, FromPrimitive, ToPrimitive, EnumIter, Eq, PartialEq, Ord, PartialOrd, Hash,
 )]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "String"),
+    serde(into = "String")
+)]
 pub enum Value {
     Two = 0,
     Three = 1,
@@ -125,6 +146,56 @@ impl Value {
     pub fn is_face_card(&self) -> bool {
         matches!(self, Self::Jack | Self::Queen | Self::King)
     }
+
+    /// Returns every Value from `low` up to and including an Ace
+    ///
+    /// The Values are sorted in ascending order, treating Aces as high. This is useful for
+    /// expanding ranges such as "TT+" or broadways.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use playing_cards::core::Value;
+    ///
+    /// assert_eq!(
+    ///     Value::all_from(Value::Jack),
+    ///     vec![Value::Jack, Value::Queen, Value::King, Value::Ace]
+    /// );
+    /// ```
+    pub fn all_from(low: Value) -> Vec<Value> {
+        Value::iter().filter(|&value| value >= low).collect()
+    }
+
+    /// Returns every Value from a Two up to and including `high`
+    ///
+    /// The Values are sorted in ascending order, treating Aces as high.
+    pub fn all_to(high: Value) -> Vec<Value> {
+        Value::iter().filter(|&value| value <= high).collect()
+    }
+
+    /// Returns the minimum distance in rank between two Values
+    ///
+    /// Since an Ace can play both high and low in a straight, the distance is wheel-aware (e.g.
+    /// both Ace-King and Ace-Two have a distance of 1). This is useful for building ranges like
+    /// suited connectors (distance of 1) or one-gappers (distance of 2).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use playing_cards::core::Value;
+    ///
+    /// assert_eq!(Value::rank_distance(Value::Ace, Value::Two), 1);
+    /// assert_eq!(Value::rank_distance(Value::Nine, Value::Jack), 2);
+    /// ```
+    pub fn rank_distance(a: Value, b: Value) -> u8 {
+        let high_distance = (a as i8 - b as i8).unsigned_abs();
+        // an ace playing low sits just below the two
+        let ace_low = |value: Value| match value {
+            Self::Ace => -1,
+            _ => value as i8,
+        };
+        let low_distance = (ace_low(a) - ace_low(b)).unsigned_abs();
+
+        high_distance.min(low_distance)
+    }
 }
 
 impl TryFrom<i32> for Value {
@@ -153,6 +224,27 @@ impl From<Value> for char {
     }
 }
 
+impl TryFrom<String> for Value {
+    type Error = String;
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Value::try_from(c)
+                .map_err(|c| format!(r#"Card value "{}" was not a valid character"#, c)),
+            _ => Err(format!(
+                r#"Card value string "{}" is not exactly a length of 1"#,
+                s
+            )),
+        }
+    }
+}
+
+impl From<Value> for String {
+    fn from(value: Value) -> Self {
+        value.get_char().to_string()
+    }
+}
+
 impl std::fmt::Display for Value {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         let c: char = self.get_char();
@@ -165,10 +257,15 @@ impl std::fmt::Display for Value {
 /// Numerical value is just for distinction and each suit has equal strength.
 ///
 /// With the `serde` feature enabled, this struct also implements serde's `Serialize` and
-/// `Deserialize` traits.
+/// `Deserialize` traits. A Suit is serialized as its lowercase letter (e.g. "s" for spades).
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Copy, FromPrimitive, ToPrimitive, EnumIter, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "String"),
+    serde(into = "String")
+)]
 pub enum Suit {
     Heart = 0,
     Club = 1,
@@ -231,6 +328,27 @@ impl From<Suit> for char {
     }
 }
 
+impl TryFrom<String> for Suit {
+    type Error = String;
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Suit::try_from(c)
+                .map_err(|c| format!(r#"Card suit "{}" was not a valid character"#, c)),
+            _ => Err(format!(
+                r#"Card suit string "{}" is not exactly a length of 1"#,
+                s
+            )),
+        }
+    }
+}
+
+impl From<Suit> for String {
+    fn from(value: Suit) -> Self {
+        value.get_char().to_string()
+    }
+}
+
 impl std::fmt::Display for Suit {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         let symbol = match *self {
@@ -243,6 +361,19 @@ impl std::fmt::Display for Suit {
     }
 }
 
+/// Error type for parsing a string of cards into a fixed number of cards
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Error)]
+pub enum CardParseError {
+    /// A card within the string does not have a valid value and suit
+    #[non_exhaustive]
+    #[error(r#"Card string "{0}" is not a valid card"#)]
+    InvalidCard(String),
+    /// The string does not have the expected number of cards
+    #[error(transparent)]
+    WrongCardCount(#[from] CardCountError),
+}
+
 /// A structural representation of a playing card
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 #[cfg_attr(
@@ -259,21 +390,116 @@ pub struct Card {
 }
 
 impl Card {
+    /// Creates a new Card from a value and a suit
+    ///
+    /// ## Example
+    /// ```rust
+    /// use playing_cards::core::{Card, Suit, Value};
+    ///
+    /// const ACE_OF_SPADES: Card = Card::new(Value::Ace, Suit::Spade);
+    ///
+    /// assert_eq!(ACE_OF_SPADES.to_int(), 52);
+    /// ```
+    pub const fn new(value: Value, suit: Suit) -> Card {
+        Card { value, suit }
+    }
+
     /// Takes in a string and returns back a vector of Cards
     ///
-    /// This can be used to quickly static hands that can be evaluated for testing.
+    /// This can be used to quickly static hands that can be evaluated for testing. Any whitespace
+    /// within the string is ignored, so both "AsKs" and "As Ks" are valid. Values and suits are
+    /// case-insensitive.
+    ///
+    /// An error is returned if any card within the string is not valid.
     pub fn vec_from_str(s: &str) -> Result<Vec<Card>, &str> {
-        if s.len() % 2 != 0 {
-            return Err("not a valid string");
+        Self::parse_cards(s)
+            .collect::<Result<_, _>>()
+            .map_err(|_| "not a valid string")
+    }
+
+    /// Takes in a string and returns back a fixed-size array of exactly `N` Cards
+    ///
+    /// This parses the string identically to `vec_from_str()`, but the cards are written directly
+    /// into an array so no heap allocations are made. This is useful in hot paths where hands of
+    /// a known size are repeatedly built from strings.
+    ///
+    /// An `InvalidCard` error is returned if any card within the string is not valid, and a
+    /// `WrongCardCount` error is returned if the string does not contain exactly `N` cards.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use playing_cards::core::Card;
+    ///
+    /// let hand: [Card; 5] = Card::arr_from_str("As Ks Qs Js Ts").unwrap();
+    ///
+    /// assert_eq!(hand.to_vec(), Card::vec_from_str("AsKsQsJsTs").unwrap());
+    /// assert!(Card::arr_from_str::<5>("AsKs").is_err());
+    /// ```
+    pub fn arr_from_str<const N: usize>(s: &str) -> Result<[Card; N], CardParseError> {
+        let mut cards = [Card::new(Value::Two, Suit::Heart); N];
+        let mut card_count = 0;
+
+        for card in Self::parse_cards(s) {
+            let card = card?;
+            if card_count < N {
+                cards[card_count] = card;
+            }
+            card_count += 1;
         }
 
-        let mut cards: Vec<Card> = Vec::new();
-        for i in (0..s.len()).step_by(2) {
-            let c = Card::from_str(s.get(i..i + 2).unwrap()).unwrap();
-            cards.push(c);
+        match card_count.cmp(&N) {
+            Ordering::Less => Err(CardCountError::NotEnoughCards {
+                expected_count: N,
+                actual_count: card_count,
+            }
+            .into()),
+            Ordering::Greater => Err(CardCountError::TooManyCards {
+                expected_count: N,
+                actual_count: card_count,
+            }
+            .into()),
+            Ordering::Equal => Ok(cards),
         }
+    }
 
-        Ok(cards)
+    /// Takes in a string and returns back a `SmallVec` of Cards
+    ///
+    /// This parses the string identically to `vec_from_str()`, but up to 7 cards are stored
+    /// inline, so parsing a hand and a board makes no heap allocations. Longer strings spill onto
+    /// the heap like a `Vec`.
+    ///
+    /// An `InvalidCard` error is returned if any card within the string is not valid.
+    ///
+    /// This function requires the `smallvec` feature.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use playing_cards::core::Card;
+    ///
+    /// let cards = Card::small_vec_from_str("As Ks Qs Js Ts").unwrap();
+    ///
+    /// assert!(!cards.spilled());
+    /// assert_eq!(cards.to_vec(), Card::vec_from_str("AsKsQsJsTs").unwrap());
+    /// ```
+    #[cfg(feature = "smallvec")]
+    pub fn small_vec_from_str(s: &str) -> Result<SmallVec<[Card; 7]>, CardParseError> {
+        Self::parse_cards(s).collect()
+    }
+
+    fn parse_cards(s: &str) -> impl Iterator<Item = Result<Card, CardParseError>> + '_ {
+        let mut chars = s.chars().filter(|c| !c.is_whitespace());
+        std::iter::from_fn(move || {
+            let value_char = chars.next()?;
+            let suit_char = chars.next();
+            Some(
+                match (Value::try_from(value_char), suit_char.map(Suit::try_from)) {
+                    (Ok(value), Some(Ok(suit))) => Ok(Card::new(value, suit)),
+                    _ => Err(CardParseError::InvalidCard(
+                        std::iter::once(value_char).chain(suit_char).collect(),
+                    )),
+                },
+            )
+        })
     }
 
     /// Turns card into integer
@@ -331,23 +557,11 @@ impl TryFrom<String> for Card {
 
         let mut chars = s.chars();
 
-        let value = Value::try_from(chars.next().unwrap());
-        if value.is_err() {
-            return Err(format!(
-                r#"Card value "{}" was not a valid character"#,
-                value.unwrap_err()
-            ));
-        }
-        let value = value.unwrap();
+        let value = Value::try_from(chars.next().unwrap())
+            .map_err(|c| format!(r#"Card value "{}" was not a valid character"#, c))?;
 
-        let suit = Suit::try_from(chars.next().unwrap());
-        if suit.is_err() {
-            return Err(format!(
-                r#"Card suit "{}" was not a valid character"#,
-                suit.unwrap_err()
-            ));
-        }
-        let suit = suit.unwrap();
+        let suit = Suit::try_from(chars.next().unwrap())
+            .map_err(|c| format!(r#"Card suit "{}" was not a valid character"#, c))?;
 
         Ok(Card { value, suit })
     }
@@ -378,10 +592,74 @@ impl std::fmt::Display for Card {
     }
 }
 
+/// Error type for converting a slice of cards into a fixed number of cards
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Error)]
+pub enum CardCountError {
+    /// There are less cards than expected
+    #[non_exhaustive]
+    #[error("Expected {expected_count} cards (Got instead {actual_count})")]
+    NotEnoughCards {
+        /// The expected amount of cards
+        expected_count: usize,
+        /// The actual amount of cards recieved
+        actual_count: usize,
+    },
+    /// There are more cards than expected
+    #[non_exhaustive]
+    #[error("Expected {expected_count} cards (Got instead {actual_count})")]
+    TooManyCards {
+        /// The expected amount of cards
+        expected_count: usize,
+        /// The actual amount of cards recieved
+        actual_count: usize,
+    },
+}
+
+/// Converts a slice of exactly 5 cards into a fixed-size array
+///
+/// This is useful for passing hands into evaluators that only accept 5 cards. If the slice does
+/// not contain exactly 5 cards, then either a `NotEnoughCards` or a `TooManyCards` will return
+/// respective to whether not enough or too many cards were given. With the `poker` feature
+/// enabled, a `CardCountError` converts into the matching `EvaluatorError`.
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::core::{exactly_five, Card};
+///
+/// let cards = Card::vec_from_str("AsKsQsJsTs").unwrap();
+///
+/// let hand: [Card; 5] = exactly_five(&cards).unwrap();
+///
+/// assert_eq!(hand[0], cards[0]);
+/// assert!(exactly_five(&cards[..4]).is_err());
+/// ```
+pub fn exactly_five(cards: &[Card]) -> Result<[Card; 5], CardCountError> {
+    const CARD_COUNT: usize = 5;
+    match cards.len().cmp(&CARD_COUNT) {
+        Ordering::Less => Err(CardCountError::NotEnoughCards {
+            expected_count: CARD_COUNT,
+            actual_count: cards.len(),
+        }),
+        Ordering::Greater => Err(CardCountError::TooManyCards {
+            expected_count: CARD_COUNT,
+            actual_count: cards.len(),
+        }),
+        Ordering::Equal => Ok([cards[0], cards[1], cards[2], cards[3], cards[4]]),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn constants_match_variant_counts() {
+        assert_eq!(NUM_VALUES, Value::iter().count());
+        assert_eq!(NUM_SUITS, Suit::iter().count());
+        assert_eq!(DECK_SIZE, NUM_VALUES * NUM_SUITS);
+    }
+
     #[test]
     fn from_card() {
         let card = Card {
@@ -428,6 +706,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_ten_either_case() {
+        for card_str in ["Th", "th"] {
+            let card = Card::from_str(card_str).unwrap();
+            assert_eq!(card.value, Value::Ten);
+        }
+        assert_eq!(Value::from_char('t'), Some(Value::Ten));
+        assert_eq!(Value::from_char('T'), Some(Value::Ten));
+    }
+
+    #[test]
+    fn vec_from_str_with_spaces() {
+        let expected_cards = Card::vec_from_str("AsKs").unwrap();
+
+        assert_eq!(Card::vec_from_str("As Ks").unwrap(), expected_cards);
+        assert_eq!(Card::vec_from_str(" As  Ks ").unwrap(), expected_cards);
+        assert_eq!(
+            Card::vec_from_str("As Ks td").unwrap(),
+            Card::vec_from_str("AsKsTd").unwrap()
+        );
+    }
+
+    #[test]
+    fn vec_from_str_invalid() {
+        assert_eq!(Card::vec_from_str("As K"), Err("not a valid string"));
+        assert_eq!(Card::vec_from_str("As Kx"), Err("not a valid string"));
+    }
+
     #[test]
     fn conversion_error() {
         assert_eq!(
@@ -445,4 +751,196 @@ mod tests {
             r#"Card string "abc" is not exactly a length of 2"#,
         );
     }
+
+    #[test]
+    fn exactly_five_cards() {
+        let cards = Card::vec_from_str("AsKsQsJsTs9s").unwrap();
+
+        assert_eq!(
+            exactly_five(&cards[..4]),
+            Err(CardCountError::NotEnoughCards {
+                expected_count: 5,
+                actual_count: 4,
+            })
+        );
+        assert_eq!(
+            exactly_five(&cards[..5]),
+            Ok([cards[0], cards[1], cards[2], cards[3], cards[4]])
+        );
+        assert_eq!(
+            exactly_five(&cards),
+            Err(CardCountError::TooManyCards {
+                expected_count: 5,
+                actual_count: 6,
+            })
+        );
+    }
+
+    #[test]
+    fn rank_distance() {
+        assert_eq!(Value::rank_distance(Value::Ace, Value::Two), 1);
+        assert_eq!(Value::rank_distance(Value::Two, Value::Ace), 1);
+        assert_eq!(Value::rank_distance(Value::Ace, Value::King), 1);
+        assert_eq!(Value::rank_distance(Value::Ace, Value::Five), 4);
+        assert_eq!(Value::rank_distance(Value::Ace, Value::Eight), 6);
+        assert_eq!(Value::rank_distance(Value::Ace, Value::Ace), 0);
+        assert_eq!(Value::rank_distance(Value::Seven, Value::Eight), 1);
+        assert_eq!(Value::rank_distance(Value::Two, Value::King), 11);
+    }
+
+    #[test]
+    fn arr_from_str_five_cards() {
+        let hand: [Card; 5] = Card::arr_from_str("2h 5d as ad 2S").expect("Failed parsing hand");
+
+        assert_eq!(hand.to_vec(), Card::vec_from_str("2h5dAsAd2s").unwrap());
+    }
+
+    #[test]
+    fn arr_from_str_errors() {
+        assert_eq!(
+            Card::arr_from_str::<5>("2h5dAsAd"),
+            Err(CardParseError::WrongCardCount(
+                CardCountError::NotEnoughCards {
+                    expected_count: 5,
+                    actual_count: 4,
+                }
+            ))
+        );
+        assert_eq!(
+            Card::arr_from_str::<5>("2h5dAsAdKsQs"),
+            Err(CardParseError::WrongCardCount(
+                CardCountError::TooManyCards {
+                    expected_count: 5,
+                    actual_count: 6,
+                }
+            ))
+        );
+        assert_eq!(
+            Card::arr_from_str::<2>("2h5"),
+            Err(CardParseError::InvalidCard("5".to_string()))
+        );
+        assert_eq!(
+            Card::arr_from_str::<2>("2h5x"),
+            Err(CardParseError::InvalidCard("5x".to_string()))
+        );
+        assert_eq!(Card::arr_from_str::<0>(""), Ok([]));
+        assert_eq!(
+            Card::arr_from_str::<2>("2h5x").unwrap_err().to_string(),
+            r#"Card string "5x" is not a valid card"#
+        );
+    }
+
+    #[cfg(feature = "smallvec")]
+    #[test]
+    fn small_vec_from_str_cards() {
+        let cards = Card::small_vec_from_str("2h 5d as ad 2S").expect("Failed parsing hand");
+
+        assert!(!cards.spilled());
+        assert_eq!(cards.to_vec(), Card::vec_from_str("2h5dAsAd2s").unwrap());
+
+        let cards = Card::small_vec_from_str("2h5dAsAd2sKsQsJs").expect("Failed parsing hand");
+
+        assert!(cards.spilled());
+        assert_eq!(cards.len(), 8);
+
+        assert_eq!(
+            Card::small_vec_from_str("2h5x"),
+            Err(CardParseError::InvalidCard("5x".to_string()))
+        );
+    }
+
+    #[test]
+    fn value_ranges() {
+        assert_eq!(
+            Value::all_from(Value::Jack),
+            vec![Value::Jack, Value::Queen, Value::King, Value::Ace]
+        );
+        assert_eq!(Value::all_from(Value::Ace), vec![Value::Ace]);
+        assert_eq!(Value::all_from(Value::Two).len(), 13);
+
+        assert_eq!(
+            Value::all_to(Value::Four),
+            vec![Value::Two, Value::Three, Value::Four]
+        );
+        assert_eq!(Value::all_to(Value::Two), vec![Value::Two]);
+        assert_eq!(Value::all_to(Value::Ace), Value::all_from(Value::Two));
+    }
+
+    #[test]
+    fn new_card() {
+        let card = Card::new(Value::Ace, Suit::Spade);
+
+        assert_eq!(card.to_int(), 52);
+        assert_eq!(
+            card,
+            Card {
+                value: Value::Ace,
+                suit: Suit::Spade
+            }
+        );
+        assert_eq!(Card::new(Value::Two, Suit::Heart), Card::from(1));
+    }
+
+    #[test]
+    fn value_string_round_trip() {
+        for value in Value::iter() {
+            let s = String::from(value);
+            assert_eq!(s, value.get_char().to_string());
+            assert_eq!(Value::try_from(s), Ok(value));
+        }
+        assert_eq!(String::from(Value::Ace), "A");
+        assert_eq!(Value::try_from("t".to_string()), Ok(Value::Ten));
+
+        assert_eq!(
+            Value::try_from("x".to_string()),
+            Err(r#"Card value "x" was not a valid character"#.to_string())
+        );
+        assert_eq!(
+            Value::try_from("10".to_string()),
+            Err(r#"Card value string "10" is not exactly a length of 1"#.to_string())
+        );
+    }
+
+    #[test]
+    fn suit_string_round_trip() {
+        for suit in Suit::iter() {
+            let s = String::from(suit);
+            assert_eq!(s, suit.get_char().to_string());
+            assert_eq!(Suit::try_from(s), Ok(suit));
+        }
+        assert_eq!(String::from(Suit::Spade), "s");
+
+        assert_eq!(
+            Suit::try_from("y".to_string()),
+            Err(r#"Card suit "y" was not a valid character"#.to_string())
+        );
+        assert_eq!(
+            Suit::try_from("".to_string()),
+            Err(r#"Card suit string "" is not exactly a length of 1"#.to_string())
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn value_and_suit_serde_round_trip() {
+        for value in Value::iter() {
+            let json = serde_json::to_string(&value).expect("Value could not be serialized");
+            assert_eq!(json, format!(r#""{}""#, value.get_char()));
+            assert_eq!(
+                serde_json::from_str::<Value>(&json).expect("Value could not be deserialized"),
+                value
+            );
+        }
+        for suit in Suit::iter() {
+            let json = serde_json::to_string(&suit).expect("Suit could not be serialized");
+            assert_eq!(json, format!(r#""{}""#, suit.get_char()));
+            assert_eq!(
+                serde_json::from_str::<Suit>(&json).expect("Suit could not be deserialized"),
+                suit
+            );
+        }
+
+        assert!(serde_json::from_str::<Value>(r#""AK""#).is_err());
+        assert!(serde_json::from_str::<Suit>(r#""x""#).is_err());
+    }
 }
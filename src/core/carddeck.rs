@@ -8,12 +8,13 @@ use serde::{Deserialize, Serialize};
 extern crate rand;
 
 use rand::seq::SliceRandom;
-use rand_core::SeedableRng;
+use rand::Rng;
+use rand_core::{RngCore, SeedableRng};
 use rand_xoshiro::Xoshiro256PlusPlus;
 
 use strum::IntoEnumIterator;
 
-use super::{Card, Suit, Value};
+use super::{Card, Suit, Value, DECK_SIZE};
 
 /// Error type for `CardDeck`
 #[non_exhaustive]
@@ -50,6 +51,11 @@ pub struct CardDeck {
     deck: Vec<Card>,
     seed: Option<[u8; 32]>,
     muck: Vec<Card>,
+    // decks serialized before dealt cards were tracked have no history
+    #[cfg_attr(feature = "serde", serde(default))]
+    dealt: Vec<Card>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    burns: Vec<Card>,
 }
 
 impl Default for CardDeck {
@@ -83,12 +89,10 @@ impl CardDeck {
     /// ```
     ///
     /// ```rust
-    /// use playing_cards::core::CardDeck;
+    /// use playing_cards::core::{seed_from_u32, CardDeck};
     ///
     /// for _ in 0..10 {
-    ///     let mut seed_bytes = Vec::from(1337_u32.to_ne_bytes());
-    ///     seed_bytes.extend_from_slice(&[0u8; 28]);
-    ///     let mut deck = CardDeck::new(Some(seed_bytes.as_slice().try_into().unwrap())).unwrap();
+    ///     let mut deck = CardDeck::new(Some(seed_from_u32(1337))).unwrap();
     ///
     ///     // Every single line should produce the same 5 cards in the same exact order because
     ///     // we gave each deck the same seed.
@@ -98,12 +102,10 @@ impl CardDeck {
     /// ```
     ///
     /// ```rust
-    /// use playing_cards::core::CardDeck;
+    /// use playing_cards::core::{seed_from_u32, CardDeck};
     ///
     /// for i in 0..10 {
-    ///     let mut seed_bytes = Vec::from((i as u32).to_ne_bytes());
-    ///     seed_bytes.extend_from_slice(&[0u8; 28]);
-    ///     let mut deck = CardDeck::new(Some(seed_bytes.as_slice().try_into().unwrap())).unwrap();
+    ///     let mut deck = CardDeck::new(Some(seed_from_u32(i))).unwrap();
     ///
     ///     // Each line should be different from one another, but if you rerun this code again,
     ///     // it will print out the exact 10 lines again.
@@ -140,6 +142,8 @@ impl CardDeck {
             deck: cards,
             seed,
             muck: Vec::new(),
+            dealt: Vec::new(),
+            burns: Vec::new(),
         };
 
         if seed.is_some() {
@@ -150,7 +154,7 @@ impl CardDeck {
     }
 
     fn create_unshuffled_deck() -> Self {
-        let mut d: Vec<Card> = Vec::with_capacity(52);
+        let mut d: Vec<Card> = Vec::with_capacity(DECK_SIZE);
 
         for s in Suit::iter() {
             for v in Value::iter() {
@@ -162,6 +166,8 @@ impl CardDeck {
             deck: d,
             seed: None,
             muck: Vec::new(),
+            dealt: Vec::new(),
+            burns: Vec::new(),
         }
     }
 
@@ -175,7 +181,7 @@ impl CardDeck {
     }
 
     fn shuffle_cards(
-        cards: &mut Vec<Card>,
+        cards: &mut [Card],
         seed: Option<[u8; 32]>,
     ) -> Result<[u8; 32], CardDeckError> {
         let mut rng;
@@ -217,6 +223,37 @@ impl CardDeck {
         removed_cards
     }
 
+    /// Removes the provided cards from the deck only if every card is found within the deck
+    ///
+    /// Returns back a list of cards that were removed from the deck in the order they were found
+    /// within the deck, identically to `strip_cards()`. If any of the provided cards are not in
+    /// the deck, the deck is left untouched and an `Err` containing the cards that were not found
+    /// is returned instead, in the same order as they were provided. This is useful for catching
+    /// mistakes when setting up a deck (e.g. stripping a card that has already been dealt).
+    ///
+    /// Example
+    /// ```rust
+    /// use playing_cards::core::{Card, CardDeck};
+    ///
+    /// let mut deck = CardDeck::default();
+    /// let hand = Card::vec_from_str("AsKs").unwrap();
+    ///
+    /// assert_eq!(deck.strip_cards_checked(&hand).unwrap().len(), 2);
+    /// assert_eq!(deck.strip_cards_checked(&hand), Err(hand));
+    /// ```
+    pub fn strip_cards_checked(&mut self, cards: &[Card]) -> Result<Vec<Card>, Vec<Card>> {
+        let missing_cards: Vec<Card> = cards
+            .iter()
+            .filter(|card| !self.deck.contains(card))
+            .cloned()
+            .collect();
+        if !missing_cards.is_empty() {
+            return Err(missing_cards);
+        }
+
+        Ok(self.strip_cards(&cards.iter().cloned().collect()))
+    }
+
     /// Searches the deck and removes cards within provided set of ranks/values
     ///
     /// Returns back a list of cards that were removed from the deck. Duplicates can be present in
@@ -322,6 +359,160 @@ impl CardDeck {
         Some(cards_dealt)
     }
 
+    /// Deals `n` cards out from the bottom of the CardDeck
+    ///
+    /// While `deal_cards()` and iterating over the deck take cards from the top of the deck, this
+    /// takes cards from the bottom, being the opposite end of the deck. Cards are returned in the
+    /// order they were dealt, so index 0 is the bottom-most card of the deck. The muck is never
+    /// used, so if there are less than `n` cards remaining in the deck, this method will return
+    /// None and no cards are removed from the deck.
+    ///
+    /// Dealt cards are recorded in `dealt_cards()` just like cards dealt from the top.
+    ///
+    /// Example
+    /// ```rust
+    /// use playing_cards::core::{Card, CardDeck};
+    ///
+    /// let mut deck: CardDeck = Default::default();
+    ///
+    /// // An unshuffled deck has the 2 of hearts at the bottom and the ace of spades at the top
+    /// assert_eq!(deck.deal_from_bottom(1).unwrap(), Card::vec_from_str("2h").unwrap());
+    /// assert_eq!(deck.deal_cards(1, false).unwrap(), Card::vec_from_str("As").unwrap());
+    /// ```
+    pub fn deal_from_bottom(&mut self, n: usize) -> Option<Vec<Card>> {
+        if n > self.deck.len() {
+            return None;
+        }
+        let cards_dealt: Vec<Card> = self.deck.drain(..n).collect();
+        self.dealt.extend_from_slice(&cards_dealt);
+
+        Some(cards_dealt)
+    }
+
+    /// Splits the top `n` cards off into a new CardDeck
+    ///
+    /// The new deck contains the removed cards in the same order, so dealing from it produces the
+    /// cards in the same order as dealing them from this deck would have. This is useful for
+    /// setting aside a stub of the deck to be used later. The new deck starts with an empty muck
+    /// and no dealt or burned cards, and the split cards are not recorded in `dealt_cards()` of
+    /// this deck. If there are less than `n` cards remaining in the deck, this method will return
+    /// None and no cards are removed from the deck.
+    ///
+    /// Example
+    /// ```rust
+    /// use playing_cards::core::{Card, CardDeck};
+    ///
+    /// let mut deck: CardDeck = Default::default();
+    ///
+    /// let mut stub = deck.split_off(2).unwrap();
+    ///
+    /// assert_eq!(deck.len(), 50);
+    /// assert_eq!(stub.deal_cards(2, false).unwrap(), Card::vec_from_str("AsKs").unwrap());
+    /// ```
+    pub fn split_off(&mut self, n: usize) -> Option<CardDeck> {
+        if n > self.deck.len() {
+            return None;
+        }
+        let split_cards = self.deck.split_off(self.deck.len() - n);
+
+        Some(Self {
+            deck: split_cards,
+            seed: None,
+            muck: Vec::new(),
+            dealt: Vec::new(),
+            burns: Vec::new(),
+        })
+    }
+
+    /// Removes `n` uniformly random cards from anywhere within the CardDeck
+    ///
+    /// Unlike `deal_cards()`, cards are not taken from the top of the deck. The order of the cards
+    /// left in the deck is preserved and the muck is never used. This is useful for sampling
+    /// cards without disturbing the rest of the deck (e.g. dealing a random flop for a quiz). If
+    /// there are less than `n` cards remaining in the deck, this method will return None and no
+    /// cards are removed from the deck.
+    ///
+    /// Removed cards are recorded in `dealt_cards()` in the order they were taken.
+    ///
+    /// Example
+    /// ```rust
+    /// use playing_cards::core::CardDeck;
+    /// use rand_core::SeedableRng;
+    /// use rand_xoshiro::Xoshiro256PlusPlus;
+    ///
+    /// let mut deck: CardDeck = Default::default();
+    /// let mut rng = Xoshiro256PlusPlus::seed_from_u64(7);
+    ///
+    /// let flop = deck.take_random(3, &mut rng).unwrap();
+    ///
+    /// assert_eq!(flop.len(), 3);
+    /// assert_eq!(deck.len(), 49);
+    /// ```
+    pub fn take_random(&mut self, n: usize, rng: &mut dyn RngCore) -> Option<Vec<Card>> {
+        if n > self.deck.len() {
+            return None;
+        }
+        let cards_taken: Vec<Card> = (0..n)
+            .map(|_| {
+                let idx = rng.gen_range(0..self.deck.len());
+                self.deck.remove(idx)
+            })
+            .collect();
+        self.dealt.extend_from_slice(&cards_taken);
+
+        Some(cards_taken)
+    }
+
+    /// Burns a card and then deals `n` cards out from the CardDeck
+    ///
+    /// The burned card is taken from the top of the deck and is tracked separately from the muck
+    /// and from the dealt cards (see `burned_cards()`). If `include_muck` is true and the deck does
+    /// not have enough cards to both burn and deal, the muck is reshuffled back into the deck
+    /// first. If there are still not enough cards, this method will return None and no cards are
+    /// removed from the deck.
+    ///
+    /// Similar to `reshuffle_muck()`, this function takes in an optional seed that is used if the
+    /// muck needs to be reshuffled. If no seed is provided, a seed will be sampled from entropy,
+    /// so a seed should be provided to keep a seeded deck reproducible.
+    ///
+    /// Example
+    /// ```rust
+    /// use playing_cards::core::CardDeck;
+    ///
+    /// let mut deck: CardDeck = Default::default();
+    /// deck.shuffle(None).unwrap();
+    ///
+    /// let flop = deck.burn_and_deal(3, false, None).unwrap();
+    ///
+    /// assert_eq!(flop.len(), 3);
+    /// assert_eq!(deck.burned_cards().len(), 1);
+    /// ```
+    pub fn burn_and_deal(
+        &mut self,
+        cards_to_deal: usize,
+        include_muck: bool,
+        seed: Option<[u8; 32]>,
+    ) -> Option<Vec<Card>> {
+        if !self.check_deal_cards(cards_to_deal + 1, include_muck) {
+            return None;
+        }
+        if self.deck.len() < cards_to_deal + 1 {
+            self.reshuffle_muck(seed).ok()?;
+        }
+        let burned_card = self.deck.pop()?;
+        self.burns.push(burned_card);
+
+        self.deal_cards(cards_to_deal, include_muck)
+    }
+
+    /// Returns all of the cards that have been burned from the CardDeck
+    ///
+    /// Cards are listed in the order they were burned. Burned cards are never placed into the muck,
+    /// so they will not be reshuffled back into the deck by `reshuffle_muck()`.
+    pub fn burned_cards(&self) -> &[Card] {
+        &self.burns
+    }
+
     /// Draws `n` cards out from the CardDeck
     ///
     /// The definition of drawing in this case means to discard and replace cards. This function
@@ -349,6 +540,15 @@ impl CardDeck {
         self.deal_cards(cards_to_deal, include_muck)
     }
 
+    /// Returns all of the cards that have been dealt out from the CardDeck
+    ///
+    /// Cards are listed in the order they were dealt, with index 0 being the first card dealt.
+    /// This includes every card pulled from the deck by `deal_cards()`, `draw_cards()`, or by
+    /// iterating over the deck, regardless of whether they were mucked afterwards.
+    pub fn dealt_cards(&self) -> &[Card] {
+        &self.dealt
+    }
+
     /// Reshuffles the muck and inserts those cards into the deck
     ///
     /// The muck will be placed behind the remaining cards in the deck.
@@ -370,22 +570,28 @@ impl Iterator for CardDeck {
     type Item = Card;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.deck.pop()
+        let card = self.deck.pop()?;
+        self.dealt.push(card);
+        Some(card)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.deck.len(), Some(self.deck.len()))
     }
 }
 
+impl ExactSizeIterator for CardDeck {}
+
 #[cfg(test)]
 mod tests {
-    use super::super::Value;
+    use super::super::{seed_from_u32, Value};
     use super::*;
     use rayon::prelude::*;
 
     #[test]
     fn test_deck_same_seed() {
-        let mut seed_bytes = Vec::from(233_i32.to_le_bytes());
-        seed_bytes.extend_from_slice(&[0u8; 28]);
-        let mut d1 = CardDeck::new(Some(seed_bytes.as_slice().try_into().unwrap())).unwrap();
-        let mut d2 = CardDeck::new(Some(seed_bytes.as_slice().try_into().unwrap())).unwrap();
+        let mut d1 = CardDeck::new(Some(seed_from_u32(233))).unwrap();
+        let mut d2 = CardDeck::new(Some(seed_from_u32(233))).unwrap();
 
         are_decks_equal(&mut d1, &mut d2);
     }
@@ -413,12 +619,270 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_get_seed() {
-        let mut expected_seed = Vec::from(233_i32.to_le_bytes());
-        expected_seed.extend_from_slice(&[0u8; 28]);
-        let d = CardDeck::new(Some(expected_seed.as_slice().try_into().unwrap())).unwrap();
+        let expected_seed = seed_from_u32(233);
+        let d = CardDeck::new(Some(expected_seed)).unwrap();
+
+        assert_eq!(d.get_seed().unwrap(), expected_seed);
+    }
+
+    #[test]
+    fn test_default_deck_size() {
+        let mut deck = CardDeck::default();
+        assert_eq!(deck.len(), DECK_SIZE);
+
+        deck.deal_cards(5, false).expect("Cards could not be dealt");
+        assert_eq!(deck.len(), DECK_SIZE - 5);
+    }
+
+    #[test]
+    fn test_dealt_cards_history() {
+        let cards = Card::vec_from_str("2h5dAsAdKdJc3h8d").expect("Failed parsing card string");
+        let mut deck = CardDeck::new_custom_deck(cards, None).expect("Deck could not be created");
+
+        assert!(deck.dealt_cards().is_empty());
+
+        let first_hand = deck.deal_cards(2, false).expect("Cards could not be dealt");
+        let second_hand = deck.deal_cards(3, false).expect("Cards could not be dealt");
+        deck.muck_cards(first_hand.clone());
+
+        let mut expected_dealt = first_hand;
+        expected_dealt.extend(second_hand);
+
+        assert_eq!(
+            expected_dealt,
+            Card::vec_from_str("8d3hJcKdAd").expect("Failed parsing card string")
+        );
+        assert_eq!(deck.dealt_cards(), expected_dealt.as_slice());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_deck_without_dealt_cards() {
+        let cards = Card::vec_from_str("2h5dAsAdKdJc3h8d").expect("Failed parsing card string");
+        let mut deck = CardDeck::new_custom_deck(cards, None).expect("Deck could not be created");
+        deck.deal_cards(2, false).expect("Cards could not be dealt");
+
+        let mut json = serde_json::to_value(&deck).expect("Deck could not be serialized");
+        json.as_object_mut().unwrap().remove("dealt");
+
+        let mut legacy_deck: CardDeck =
+            serde_json::from_value(json).expect("Deck could not be deserialized");
+
+        assert!(legacy_deck.dealt_cards().is_empty());
+        assert_eq!(legacy_deck.len(), deck.len());
+        assert_eq!(legacy_deck.next(), deck.next());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialize_deck_without_burned_cards() {
+        let cards = Card::vec_from_str("2h5dAsAdKdJc3h8d").expect("Failed parsing card string");
+        let mut deck = CardDeck::new_custom_deck(cards, None).expect("Deck could not be created");
+        deck.burn_and_deal(2, false, None)
+            .expect("Cards could not be dealt");
+
+        let mut json = serde_json::to_value(&deck).expect("Deck could not be serialized");
+        json.as_object_mut().unwrap().remove("burns");
+
+        let mut legacy_deck: CardDeck =
+            serde_json::from_value(json).expect("Deck could not be deserialized");
+
+        assert!(legacy_deck.burned_cards().is_empty());
+        assert_eq!(legacy_deck.len(), deck.len());
+        assert_eq!(legacy_deck.next(), deck.next());
+    }
+
+    #[test]
+    fn golden_shuffle_order() {
+        // If this test fails, the shuffling algorithm has changed and decks created with the same
+        // seed on older versions of the crate will no longer produce the same order of cards.
+        let seed: [u8; 32] = [
+            0x70, 0x6c, 0x61, 0x79, 0x69, 0x6e, 0x67, 0x2d, 0x63, 0x61, 0x72, 0x64, 0x73, 0x00,
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+            0x0f, 0x10, 0x11, 0x12,
+        ];
+        let mut deck = CardDeck::new(Some(seed)).unwrap();
+
+        let hand = deck.deal_cards(5, false).unwrap();
+
+        assert_eq!(hand, Card::vec_from_str("Tc3c2dTdQd").unwrap());
+    }
+
+    #[test]
+    fn test_strip_cards_checked() {
+        let mut deck = CardDeck::default();
+        deck.strip_cards(&HashSet::from_iter(Card::vec_from_str("2h3h").unwrap()));
+
+        let mixed = Card::vec_from_str("As2hKd3h").unwrap();
+        assert_eq!(
+            deck.strip_cards_checked(&mixed),
+            Err(Card::vec_from_str("2h3h").unwrap())
+        );
+        assert_eq!(deck.len(), 50, "Deck should be untouched on error");
+
+        let present = Card::vec_from_str("AsKd").unwrap();
+        let removed = deck
+            .strip_cards_checked(&present)
+            .expect("Cards were not found in the deck");
+        assert_eq!(
+            removed.iter().collect::<HashSet<_>>(),
+            present.iter().collect::<HashSet<_>>()
+        );
+        assert_eq!(deck.len(), 48);
+        assert_eq!(deck.strip_cards_checked(&[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_split_off() {
+        let seed = crate::core::seed_from_u64(2506);
+        let known_order: Vec<Card> = CardDeck::new(Some(seed)).unwrap().collect();
+        let mut deck = CardDeck::new(Some(seed)).unwrap();
+
+        let mut stub = deck.split_off(10).expect("Deck could not be split");
+
+        assert_eq!(deck.len(), 42);
+        assert_eq!(stub.len(), 10);
+        assert!(deck.dealt_cards().is_empty());
+        assert_eq!(stub.deal_cards(10, false).unwrap(), known_order[..10]);
+        assert_eq!(deck.collect::<Vec<_>>(), known_order[10..]);
+
+        let mut deck = CardDeck::default();
+        assert!(deck.split_off(53).is_none());
+        assert_eq!(deck.len(), 52);
+        assert_eq!(deck.split_off(0).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_take_random() {
+        let mut deck = CardDeck::default();
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(2484);
+
+        let taken = deck.take_random(5, &mut rng).unwrap();
+
+        assert_eq!(taken.len(), 5);
+        assert_eq!(deck.len(), 47);
+        assert_eq!(taken.iter().collect::<HashSet<_>>().len(), 5);
+        let remaining: Vec<Card> = deck.clone().collect();
+        for card in &taken {
+            assert!(!remaining.contains(card), "{} is still in the deck", card);
+        }
+        assert_eq!(deck.dealt_cards(), taken);
+
+        assert_eq!(deck.take_random(48, &mut rng), None);
+        assert_eq!(deck.len(), 47);
+    }
+
+    #[test]
+    fn test_deal_from_bottom() {
+        let seed = seed_from_u32(233);
+        let known_order: Vec<Card> = CardDeck::new(Some(seed)).unwrap().collect();
+        let mut deck = CardDeck::new(Some(seed)).unwrap();
+
+        let top = deck.deal_cards(3, false).unwrap();
+        let bottom = deck.deal_from_bottom(3).unwrap();
+
+        assert_eq!(top, known_order[..3]);
+        assert_eq!(
+            bottom,
+            known_order[49..].iter().rev().cloned().collect::<Vec<_>>()
+        );
+        assert!(top.iter().all(|card| !bottom.contains(card)));
+        assert_eq!(deck.len(), 46);
+        assert_eq!(deck.dealt_cards(), [top, bottom].concat());
+
+        assert_eq!(deck.deal_from_bottom(47), None);
+        assert_eq!(deck.len(), 46);
+    }
+
+    #[test]
+    fn test_burn_and_deal_reshuffles_muck() {
+        let cards = Card::vec_from_str("2h5dAsAd").expect("Failed parsing card string");
+        let mut deck = CardDeck::new_custom_deck(cards, None).expect("Deck could not be created");
+
+        let hand = deck.deal_cards(3, false).expect("Cards could not be dealt");
+        deck.muck_cards(hand.clone());
+
+        assert_eq!(deck.burn_and_deal(3, false, None), None);
+        assert_eq!(deck.len(), 1);
+
+        assert_eq!(deck.burn_and_deal(4, true, None), None);
+        assert_eq!(deck.len(), 1);
+
+        let dealt = deck
+            .burn_and_deal(3, true, None)
+            .expect("Cards could not be dealt");
+
+        // the card left in the deck stays on top of the reshuffled muck, so it is burned
+        assert_eq!(
+            deck.burned_cards(),
+            Card::vec_from_str("2h").expect("Failed parsing card string")
+        );
+        assert_eq!(dealt.len(), 3);
+        assert!(hand.iter().all(|card| dealt.contains(card)));
+        assert_eq!(deck.len(), 0);
+        assert!(deck.muck.is_empty());
+    }
+
+    #[test]
+    fn test_burn_and_deal_seeded_reshuffle() {
+        let cards = Card::vec_from_str("2h5dAsAdKdJc3h8d").expect("Failed parsing card string");
+        let mut deck = CardDeck::new_custom_deck(cards, None).expect("Deck could not be created");
+        let hand = deck.deal_cards(6, false).expect("Cards could not be dealt");
+        deck.muck_cards(hand);
+
+        let mut d1 = deck.clone();
+        let mut d2 = deck.clone();
+        let mut manual = deck;
+
+        let dealt1 = d1.burn_and_deal(4, true, Some(seed_from_u32(2463)));
+        let dealt2 = d2.burn_and_deal(4, true, Some(seed_from_u32(2463)));
+        manual
+            .reshuffle_muck(Some(seed_from_u32(2463)))
+            .expect("Muck could not be reshuffled");
+        let manual_dealt = manual.burn_and_deal(4, false, None);
+
+        assert!(dealt1.is_some());
+        assert_eq!(dealt1, dealt2);
+        assert_eq!(dealt1, manual_dealt);
+        assert_eq!(d1.burned_cards(), manual.burned_cards());
+    }
+
+    #[test]
+    fn test_burns_tracked_separately_from_muck() {
+        let cards = Card::vec_from_str("2h5dAsAdKdJc3h8d").expect("Failed parsing card string");
+        let mut deck = CardDeck::new_custom_deck(cards, None).expect("Deck could not be created");
+
+        let hand = deck.deal_cards(2, false).expect("Cards could not be dealt");
+        let flop = deck
+            .burn_and_deal(3, false, None)
+            .expect("Cards could not be dealt");
+        let turn = deck
+            .burn_and_deal(1, false, None)
+            .expect("Cards could not be dealt");
+        deck.muck_cards(hand.clone());
+
+        assert_eq!(
+            deck.burned_cards(),
+            Card::vec_from_str("Jc5d").expect("Failed parsing card string")
+        );
+        assert_eq!(
+            flop,
+            Card::vec_from_str("KdAdAs").expect("Failed parsing card string")
+        );
+        assert_eq!(
+            turn,
+            Card::vec_from_str("2h").expect("Failed parsing card string")
+        );
+        assert_eq!(deck.muck, hand);
+        for burned_card in deck.burned_cards() {
+            assert!(!deck.muck.contains(burned_card));
+            assert!(!deck.dealt_cards().contains(burned_card));
+        }
 
-        assert_eq!(Vec::from(d.get_seed().unwrap()), expected_seed);
+        // No cards are left in the deck, so there is nothing left to burn
+        assert_eq!(deck.burn_and_deal(0, false, None), None);
     }
 
     #[test]
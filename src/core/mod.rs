@@ -5,3 +5,9 @@ pub use carddeck::*;
 
 mod card;
 pub use card::*;
+
+mod bitdeck;
+pub use bitdeck::*;
+
+mod seed;
+pub use seed::*;
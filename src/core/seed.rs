@@ -0,0 +1,67 @@
+/// Creates a 256-bit deck seed from a 64-bit integer
+///
+/// The integer is written into the first 8 bytes of the seed in little-endian byte order, and the
+/// remaining 24 bytes are zero-padded. The same integer will always produce the same seed
+/// regardless of the platform.
+///
+/// Please note that a 64-bit integer can only produce a very small fraction of all possible deck
+/// permutations. This is intended for reproducible tests and examples, not for games where the
+/// deck order must be unpredictable.
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::core::{seed_from_u64, CardDeck};
+///
+/// let mut d1 = CardDeck::new(Some(seed_from_u64(1337))).unwrap();
+/// let mut d2 = CardDeck::new(Some(seed_from_u64(1337))).unwrap();
+///
+/// assert_eq!(d1.deal_cards(5, false), d2.deal_cards(5, false));
+/// ```
+pub fn seed_from_u64(n: u64) -> [u8; 32] {
+    let mut seed = [0u8; 32];
+    seed[..8].copy_from_slice(&n.to_le_bytes());
+    seed
+}
+
+/// Creates a 256-bit deck seed from a 32-bit integer
+///
+/// The integer is written into the first 4 bytes of the seed in little-endian byte order, and the
+/// remaining 28 bytes are zero-padded. This produces the same seed as `seed_from_u64(n as u64)`.
+pub fn seed_from_u32(n: u32) -> [u8; 32] {
+    seed_from_u64(n as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::CardDeck;
+    use super::*;
+
+    #[test]
+    fn same_input_same_seed() {
+        assert_eq!(seed_from_u64(233), seed_from_u64(233));
+        assert_eq!(seed_from_u32(233), seed_from_u32(233));
+        assert_eq!(seed_from_u32(233), seed_from_u64(233));
+        assert_ne!(seed_from_u64(233), seed_from_u64(234));
+    }
+
+    #[test]
+    fn seed_byte_order() {
+        let seed = seed_from_u64(0x0102_0304_0506_0708);
+
+        assert_eq!(seed[..8], [0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01]);
+        assert!(seed[8..].iter().all(|&byte| byte == 0));
+    }
+
+    #[test]
+    fn matches_manual_padding() {
+        let mut seed_bytes = Vec::from(233_u32.to_le_bytes());
+        seed_bytes.extend_from_slice(&[0u8; 28]);
+        let manual_seed: [u8; 32] = seed_bytes.as_slice().try_into().unwrap();
+
+        assert_eq!(seed_from_u32(233), manual_seed);
+
+        let d1: Vec<_> = CardDeck::new(Some(manual_seed)).unwrap().collect();
+        let d2: Vec<_> = CardDeck::new(Some(seed_from_u32(233))).unwrap().collect();
+        assert_eq!(d1, d2);
+    }
+}
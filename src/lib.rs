@@ -46,6 +46,8 @@
 //! * `core`: A default feature that includes the core module
 //! * `poker`: The poker module
 //! * `serde`: Adds serde-related traits to structs
+//! * `parallel`: Adds multi-threaded equity calculations using rayon
+//! * `smallvec`: Adds parsing cards into a `SmallVec` to avoid heap allocations
 
 #![cfg_attr(feature = "unstable", feature(test))]
 
@@ -60,5 +62,7 @@ pub mod core;
 #[cfg(feature = "poker")]
 pub mod poker;
 
+pub mod prelude;
+
 #[cfg(test)]
 mod tests {}
@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+
+use crate::core::Card;
+
+/// Returns the cards within `target` that are held by `hero`
+///
+/// This is useful for checking blockers, where holding a card prevents an opponent from holding
+/// it (e.g. holding the ace of the flush suit means no opponent can have the nut flush). The
+/// returned cards are in the same order as they appear in `target`. An empty vector is returned
+/// if hero does not block any of the target cards.
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::{core::Card, poker::blockers};
+///
+/// let hero = Card::vec_from_str("AhKd").unwrap();
+/// let target = Card::vec_from_str("AhQh").unwrap();
+///
+/// assert_eq!(blockers::blocks(&hero, &target), Card::vec_from_str("Ah").unwrap());
+/// ```
+pub fn blocks(hero: &[Card], target: &[Card]) -> Vec<Card> {
+    let hero_cards: HashSet<&Card> = hero.iter().collect();
+
+    target
+        .iter()
+        .filter(|card| hero_cards.contains(card))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ace_blocks_nut_flush() {
+        let hero = Card::vec_from_str("Ah3c").unwrap();
+        let board = Card::vec_from_str("2h7hTh").unwrap();
+        // the nut flush on this board needs the ace and king of hearts
+        let nut_flush = Card::vec_from_str("AhKh").unwrap();
+
+        let blockers = blocks(&hero, &nut_flush);
+
+        assert_eq!(blockers, Card::vec_from_str("Ah").unwrap());
+        assert!(blocks(&hero, &board).is_empty());
+    }
+
+    #[test]
+    fn no_blockers() {
+        let hero = Card::vec_from_str("2s3s").unwrap();
+        let target = Card::vec_from_str("AhKh").unwrap();
+
+        assert!(blocks(&hero, &target).is_empty());
+        assert!(blocks(&[], &target).is_empty());
+    }
+}
@@ -0,0 +1,528 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use rand::seq::SliceRandom;
+use rand_core::{RngCore, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::core::{BitDeck, Card, CardDeck};
+use crate::poker::evaluators::{high_evaluator, EvaluatorError};
+
+/// The number of iterations simulated by each independently seeded chunk
+const CHUNK_ITERATIONS: u64 = 1024;
+
+const MAXIMUM_BOARD_CARDS: usize = 5;
+
+/// The outcome of a Monte Carlo simulation for one player
+///
+/// `wins` counts the simulated runouts the player won outright, and `ties` counts the runouts
+/// where the player shared the best hand with at least one other player. `tie_shares` sums the
+/// fraction of the pot the player received from each tied runout, where a runout split between
+/// `k` players contributes `1/k` to each of them.
+///
+/// With the `serde` feature enabled, this struct also implements serde's `Serialize` and
+/// `Deserialize` traits.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EquityResult {
+    /// The number of runouts won outright
+    pub wins: u64,
+    /// The number of runouts where the best hand was shared
+    pub ties: u64,
+    /// The sum of the pot fractions received from the runouts where the best hand was shared
+    pub tie_shares: f64,
+    /// The total number of simulated runouts
+    pub iterations: u64,
+}
+
+impl EquityResult {
+    /// Returns the fraction of runouts won outright
+    ///
+    /// Returns 0 if no runouts were simulated.
+    pub fn win_rate(&self) -> f64 {
+        if self.iterations == 0 {
+            return 0.0;
+        }
+        self.wins as f64 / self.iterations as f64
+    }
+
+    /// Returns the fraction of runouts where the best hand was shared
+    ///
+    /// Returns 0 if no runouts were simulated.
+    pub fn tie_rate(&self) -> f64 {
+        if self.iterations == 0 {
+            return 0.0;
+        }
+        self.ties as f64 / self.iterations as f64
+    }
+
+    /// Returns the fraction of the pot the player is expected to win
+    ///
+    /// Unlike `win_rate()` and `tie_rate()`, ties are not counted on their own. Instead, each tied
+    /// runout gives an equal fraction of the pot to every player that shares the best hand (e.g.
+    /// a third to each of three tied players). The shares of all players in a simulation add up
+    /// to 1. Returns 0 if no runouts were simulated.
+    pub fn equity_share(&self) -> f64 {
+        if self.iterations == 0 {
+            return 0.0;
+        }
+        (self.wins as f64 + self.tie_shares) / self.iterations as f64
+    }
+
+    fn merge(&mut self, other: &EquityResult) {
+        self.wins += other.wins;
+        self.ties += other.ties;
+        self.tie_shares += other.tie_shares;
+        self.iterations += other.iterations;
+    }
+}
+
+/// Estimates the Hold'em equity of each hand by randomly completing the board
+///
+/// Each iteration deals the remaining board cards from the cards not held by any player or
+/// already on the board, and the players' high hands are then compared. Returns an
+/// `EquityResult` for each hand in the same order as `hands`.
+///
+/// Iterations are simulated in fixed-size chunks, each with its own sub-seed derived from `seed`,
+/// so the same seed will always produce the same results. The results are identical to the ones
+/// produced by `monte_carlo_parallel()`.
+///
+/// If the board contains more than 5 cards, then a `TooManyCards` error will return. If duplicate
+/// cards are found across all hands and the board, then a `FailedToCalculateRank` error will
+/// return. Any other errors from `high_evaluator::evaluate_hand()` are forwarded as is.
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::{core::Card, poker::equity};
+///
+/// let hands = [
+///     Card::vec_from_str("AsAh").unwrap(),
+///     Card::vec_from_str("7c2d").unwrap(),
+/// ];
+///
+/// let results = equity::monte_carlo(&hands, &[], 2000, [0; 32]).unwrap();
+///
+/// assert!(results[0].win_rate() > results[1].win_rate());
+/// ```
+pub fn monte_carlo(
+    hands: &[Vec<Card>],
+    board: &[Card],
+    iterations: u64,
+    seed: [u8; 32],
+) -> Result<Vec<EquityResult>, EvaluatorError> {
+    let remaining_cards = remaining_cards(hands, board)?;
+
+    let mut results = vec![EquityResult::default(); hands.len()];
+    for (chunk_iterations, chunk_seed) in chunk_seeds(iterations, seed) {
+        let chunk_results = simulate_chunk(
+            hands,
+            board,
+            remaining_cards.clone(),
+            chunk_iterations,
+            chunk_seed,
+        )?;
+        for (result, chunk_result) in results.iter_mut().zip(&chunk_results) {
+            result.merge(chunk_result);
+        }
+    }
+
+    Ok(results)
+}
+
+/// Estimates the Hold'em equity of each hand across multiple threads
+///
+/// This behaves identically to `monte_carlo()`, but the chunks of iterations are distributed
+/// across rayon's thread pool. Since each chunk's sub-seed only depends on `seed` and the
+/// position of the chunk, the results are the same regardless of how many threads are used.
+///
+/// This function is only available with the `parallel` feature enabled.
+#[cfg(feature = "parallel")]
+pub fn monte_carlo_parallel(
+    hands: &[Vec<Card>],
+    board: &[Card],
+    iterations: u64,
+    seed: [u8; 32],
+) -> Result<Vec<EquityResult>, EvaluatorError> {
+    let remaining_cards = remaining_cards(hands, board)?;
+
+    let chunk_results = chunk_seeds(iterations, seed)
+        .into_par_iter()
+        .map(|(chunk_iterations, chunk_seed)| {
+            simulate_chunk(
+                hands,
+                board,
+                remaining_cards.clone(),
+                chunk_iterations,
+                chunk_seed,
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut results = vec![EquityResult::default(); hands.len()];
+    for chunk_result in &chunk_results {
+        for (result, chunk_result) in results.iter_mut().zip(chunk_result) {
+            result.merge(chunk_result);
+        }
+    }
+
+    Ok(results)
+}
+
+/// Deals multiple independent runouts of the board that do not share any undealt cards
+///
+/// This is typically used when running it multiple times, where every runout completes the same
+/// known board, but each card that is dealt can only appear in one of the runouts. Cards on the
+/// board and the `dead` cards (e.g. the players' hands) are never dealt. Returns `times` full
+/// boards, each starting with the known board cards.
+///
+/// If the board contains more than 5 cards, then a `TooManyCards` error will return. If
+/// duplicate cards are found across the board and the dead cards, then a `FailedToCalculateRank`
+/// error will return. If there are not enough cards left in the deck to complete every runout,
+/// then a `NotEnoughCards` error will return.
+///
+/// ## Example
+/// ```rust
+/// use rand_core::SeedableRng;
+/// use rand_xoshiro::Xoshiro256PlusPlus;
+/// use playing_cards::{core::Card, poker::equity};
+///
+/// let board = Card::vec_from_str("2c7dTh").unwrap();
+/// let dead = Card::vec_from_str("AsAhKsKh").unwrap();
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(3);
+///
+/// let runouts = equity::independent_runouts(&board, &dead, 3, &mut rng).unwrap();
+///
+/// assert_eq!(runouts.len(), 3);
+/// assert!(runouts.iter().all(|runout| runout[..3] == board[..]));
+/// ```
+pub fn independent_runouts(
+    board: &[Card],
+    dead: &[Card],
+    times: usize,
+    rng: &mut dyn RngCore,
+) -> Result<Vec<[Card; 5]>, EvaluatorError> {
+    let mut remaining_cards = remaining_cards(&[dead.to_vec()], board)?;
+
+    let cards_per_runout = MAXIMUM_BOARD_CARDS - board.len();
+    let cards_to_deal = cards_per_runout * times;
+    if cards_to_deal > remaining_cards.len() {
+        return Err(EvaluatorError::NotEnoughCards {
+            card_set_type: "Deck".to_string(),
+            expected_count: cards_to_deal as u64,
+            actual_count: remaining_cards.len() as u64,
+        });
+    }
+
+    let (dealt_cards, _) = remaining_cards.partial_shuffle(rng, cards_to_deal);
+
+    Ok((0..times)
+        .map(|i| {
+            let runout = &dealt_cards[i * cards_per_runout..(i + 1) * cards_per_runout];
+            <[Card; 5]>::try_from([board, runout].concat()).unwrap()
+        })
+        .collect())
+}
+
+/// Returns the cards that can still be dealt to the board
+fn remaining_cards(hands: &[Vec<Card>], board: &[Card]) -> Result<Vec<Card>, EvaluatorError> {
+    if board.len() > MAXIMUM_BOARD_CARDS {
+        return Err(EvaluatorError::TooManyCards {
+            card_set_type: "Board".to_string(),
+            expected_count: MAXIMUM_BOARD_CARDS as u64,
+            actual_count: board.len() as u64,
+        });
+    }
+
+    let known_cards: Vec<Card> = hands
+        .iter()
+        .flatten()
+        .chain(board.iter())
+        .cloned()
+        .collect();
+    let known_card_set = BitDeck::from_cards(&known_cards);
+    if known_cards.len() != known_card_set.count() {
+        return Err(EvaluatorError::FailedToCalculateRank(
+            "Found duplicate cards".to_string(),
+        ));
+    }
+
+    Ok(CardDeck::default()
+        .filter(|card| !known_card_set.contains(card))
+        .collect())
+}
+
+/// Splits the iterations into chunks, deriving a sub-seed for each chunk from the seed
+fn chunk_seeds(iterations: u64, seed: [u8; 32]) -> Vec<(u64, [u8; 32])> {
+    let mut rng = Xoshiro256PlusPlus::from_seed(seed);
+    let chunk_count = (iterations + CHUNK_ITERATIONS - 1) / CHUNK_ITERATIONS;
+
+    (0..chunk_count)
+        .map(|chunk| {
+            let mut chunk_seed = [0u8; 32];
+            rng.fill_bytes(&mut chunk_seed);
+            let chunk_iterations = CHUNK_ITERATIONS.min(iterations - chunk * CHUNK_ITERATIONS);
+            (chunk_iterations, chunk_seed)
+        })
+        .collect()
+}
+
+fn simulate_chunk(
+    hands: &[Vec<Card>],
+    board: &[Card],
+    mut remaining_cards: Vec<Card>,
+    iterations: u64,
+    seed: [u8; 32],
+) -> Result<Vec<EquityResult>, EvaluatorError> {
+    let mut rng = Xoshiro256PlusPlus::from_seed(seed);
+    let cards_to_deal = MAXIMUM_BOARD_CARDS - board.len();
+    let mut results = vec![
+        EquityResult {
+            iterations,
+            ..Default::default()
+        };
+        hands.len()
+    ];
+
+    for _ in 0..iterations {
+        let (runout, _) = remaining_cards.partial_shuffle(&mut rng, cards_to_deal);
+
+        let strengths = hands
+            .iter()
+            .map(|hand| {
+                high_evaluator::evaluate_iter(
+                    hand.iter()
+                        .chain(board.iter())
+                        .chain(runout.iter())
+                        .cloned(),
+                )
+                .map(|rank| rank.strength)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let best_strength = match strengths.iter().max() {
+            Some(&strength) => strength,
+            None => break,
+        };
+        let winner_count = strengths.iter().filter(|&&s| s == best_strength).count();
+        for (result, &strength) in results.iter_mut().zip(&strengths) {
+            if strength != best_strength {
+                continue;
+            }
+            if winner_count == 1 {
+                result.wins += 1;
+            } else {
+                result.ties += 1;
+                result.tie_shares += 1.0 / winner_count as f64;
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::card_mask;
+
+    fn hands(hands: &[&str]) -> Vec<Vec<Card>> {
+        hands
+            .iter()
+            .map(|hand| Card::vec_from_str(hand).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn same_seed_same_results() {
+        let hands = hands(&["AsKs", "QdQc"]);
+        let board = Card::vec_from_str("2s7sTd").unwrap();
+
+        let results = monte_carlo(&hands, &board, 3000, [7; 32]).expect("Simulation failed");
+
+        assert_eq!(
+            results,
+            monte_carlo(&hands, &board, 3000, [7; 32]).expect("Simulation failed")
+        );
+        for result in &results {
+            assert_eq!(result.iterations, 3000);
+        }
+        assert_eq!(
+            results[0].wins + results[1].wins + results[0].ties,
+            3000,
+            "Every runout should have a winner or a tie"
+        );
+    }
+
+    #[test]
+    fn complete_board_is_deterministic() {
+        let hands = hands(&["AsAh", "KsKh", "KdKc"]);
+        let board = Card::vec_from_str("2c7dTh3s4s").unwrap();
+
+        let results = monte_carlo(&hands, &board, 10, [0; 32]).expect("Simulation failed");
+
+        assert_eq!(
+            results,
+            vec![
+                EquityResult {
+                    wins: 10,
+                    ties: 0,
+                    tie_shares: 0.0,
+                    iterations: 10,
+                },
+                EquityResult {
+                    wins: 0,
+                    ties: 0,
+                    tie_shares: 0.0,
+                    iterations: 10,
+                },
+                EquityResult {
+                    wins: 0,
+                    ties: 0,
+                    tie_shares: 0.0,
+                    iterations: 10,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn three_way_chop_shares() {
+        let hands = hands(&["AhKh", "AcKc", "QsQc"]);
+        let board = Card::vec_from_str("AdKd7c7h2s").unwrap();
+
+        let results = monte_carlo(&hands, &board, 10, [0; 32]).expect("Simulation failed");
+
+        assert_eq!(results[0].ties, 10);
+        assert_eq!(results[0].win_rate(), 0.0);
+        assert_eq!(results[0].equity_share(), 0.5);
+        assert_eq!(results[1].equity_share(), 0.5);
+        assert_eq!(results[2].equity_share(), 0.0);
+
+        // the board plays for everyone
+        let board = Card::vec_from_str("AsKsQdJdTc").unwrap();
+        let results = monte_carlo(&hands, &board, 10, [0; 32]).expect("Simulation failed");
+
+        for result in &results {
+            assert!((result.equity_share() - 1.0 / 3.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn equity_shares_sum_to_one() {
+        let hands = hands(&["AsKs", "AdKd", "7h7c"]);
+        let board = Card::vec_from_str("2c9hTs").unwrap();
+
+        let results = monte_carlo(&hands, &board, 3000, [3; 32]).expect("Simulation failed");
+
+        let total_share: f64 = results.iter().map(EquityResult::equity_share).sum();
+        assert!((total_share - 1.0).abs() < 1e-9);
+        assert!(results[0].ties > 0);
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        let hands = hands(&["AsAh", "AsKh"]);
+
+        assert_eq!(
+            monte_carlo(&hands, &[], 10, [0; 32]),
+            Err(EvaluatorError::FailedToCalculateRank(
+                "Found duplicate cards".to_string()
+            ))
+        );
+
+        let board = Card::vec_from_str("2c7dTh3s4s5s").unwrap();
+        assert_eq!(
+            monte_carlo(&hands[..1], &board, 10, [0; 32]),
+            Err(EvaluatorError::TooManyCards {
+                card_set_type: "Board".to_string(),
+                expected_count: 5,
+                actual_count: 6,
+            })
+        );
+    }
+
+    #[test]
+    fn independent_runouts_do_not_overlap() {
+        let board = Card::vec_from_str("2c7dTh").unwrap();
+        let dead = Card::vec_from_str("AsAhKsKh").unwrap();
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(2496);
+
+        let runouts =
+            independent_runouts(&board, &dead, 3, &mut rng).expect("Runouts could not be dealt");
+
+        assert_eq!(runouts.len(), 3);
+        let dealt_cards: Vec<Card> = runouts
+            .iter()
+            .flat_map(|runout| runout[3..].to_vec())
+            .collect();
+        assert_eq!(dealt_cards.len(), 6);
+        assert_eq!(card_mask(&dealt_cards).count_ones(), 6);
+        for runout in &runouts {
+            assert_eq!(runout[..3], board[..]);
+            assert!(runout[3..].iter().all(|card| !dead.contains(card)));
+        }
+    }
+
+    #[test]
+    fn independent_runouts_not_enough_cards() {
+        let dead = Card::vec_from_str("AsAh").unwrap();
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(2496);
+
+        assert_eq!(
+            independent_runouts(&[], &dead, 11, &mut rng),
+            Err(EvaluatorError::NotEnoughCards {
+                card_set_type: "Deck".to_string(),
+                expected_count: 55,
+                actual_count: 50,
+            })
+        );
+        assert_eq!(
+            independent_runouts(&[], &dead, 10, &mut rng)
+                .expect("Runouts could not be dealt")
+                .len(),
+            10
+        );
+    }
+
+    #[test]
+    fn independent_runouts_complete_board() {
+        let board = Card::vec_from_str("2c7dTh3s4s").unwrap();
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(2496);
+
+        let runouts =
+            independent_runouts(&board, &[], 2, &mut rng).expect("Runouts could not be dealt");
+
+        assert_eq!(
+            runouts,
+            vec![<[Card; 5]>::try_from(board.clone()).unwrap(); 2]
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_results_independent_of_thread_count() {
+        let hands = hands(&["AsKs", "QdQc", "7h6h"]);
+        let board = Card::vec_from_str("2s7sTd").unwrap();
+        let seed = [42; 32];
+
+        let run_with_threads = |threads: usize| {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap()
+                .install(|| monte_carlo_parallel(&hands, &board, 10_000, seed))
+                .expect("Simulation failed")
+        };
+
+        let single_thread_results = run_with_threads(1);
+
+        assert_eq!(single_thread_results, run_with_threads(4));
+        assert_eq!(
+            single_thread_results,
+            monte_carlo(&hands, &board, 10_000, seed).expect("Simulation failed")
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use crate::core::{BitDeck, Card};
+use crate::poker::evaluators::{high_evaluator, EvaluatorError};
+use crate::poker::ranks::HighRank;
+
+/// A memoizing wrapper around the high evaluator
+///
+/// Ranks are cached by a canonical `u64` key of the set of cards being evaluated, being the
+/// `BitDeck` mask of the cards. Since the key does not depend on card order, evaluating the same
+/// hole cards and board in any order will hit the same cache entry. Only successful evaluations
+/// are cached.
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::{core::Card, poker::eval_cache::CachedEvaluator};
+///
+/// let mut evaluator = CachedEvaluator::new();
+/// let hand = Card::vec_from_str("5h5s").unwrap();
+/// let board = Card::vec_from_str("2dTdKs5dAc").unwrap();
+///
+/// let rank = evaluator.evaluate_with_board(&hand, &board).unwrap();
+/// let cached_rank = evaluator.evaluate_with_board(&hand, &board).unwrap();
+///
+/// assert_eq!(rank, cached_rank);
+/// assert_eq!(evaluator.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CachedEvaluator {
+    cache: HashMap<u64, HighRank>,
+}
+
+impl CachedEvaluator {
+    /// Creates a new CachedEvaluator with an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Evaluates the high hand for the set of cards, returning the cached rank if one exists
+    ///
+    /// Errors are identical to `high_evaluator::evaluate_hand()`.
+    pub fn evaluate_hand(&mut self, cards: &[Card]) -> Result<HighRank, EvaluatorError> {
+        let bit_deck = BitDeck::from_cards(cards);
+        // duplicate cards collapse into the same bit, so they can never share a cache entry
+        if bit_deck.count() != cards.len() {
+            return high_evaluator::evaluate_hand(cards);
+        }
+
+        let key = bit_deck.mask();
+        if let Some(rank) = self.cache.get(&key) {
+            return Ok(rank.clone());
+        }
+
+        let rank = high_evaluator::evaluate_hand(cards)?;
+        self.cache.insert(key, rank.clone());
+        Ok(rank)
+    }
+
+    /// Evaluates the high hand for the player's hand and the board
+    ///
+    /// This is identical to calling `evaluate_hand()` with the player's hand and the board chained
+    /// together.
+    pub fn evaluate_with_board(
+        &mut self,
+        player_hand: &[Card],
+        board: &[Card],
+    ) -> Result<HighRank, EvaluatorError> {
+        self.evaluate_hand(&[player_hand, board].concat())
+    }
+
+    /// Returns the number of cached ranks
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Returns true if no ranks have been cached
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Removes all cached ranks
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_miss_populates_cache() {
+        let mut evaluator = CachedEvaluator::new();
+        let cards = Card::vec_from_str("AhKhQhJhTh2c3d").unwrap();
+
+        assert!(evaluator.is_empty());
+
+        let rank = evaluator.evaluate_hand(&cards).expect("Evaluation failed");
+
+        assert_eq!(evaluator.len(), 1);
+        assert_eq!(rank, high_evaluator::evaluate_hand(&cards).unwrap());
+    }
+
+    #[test]
+    fn cache_hit_returns_identical_rank() {
+        let mut evaluator = CachedEvaluator::new();
+        let hand = Card::vec_from_str("9c3s").unwrap();
+        let board = Card::vec_from_str("2d9d2c9h3h").unwrap();
+
+        let rank = evaluator
+            .evaluate_with_board(&hand, &board)
+            .expect("Evaluation failed");
+        // same cards in a different order
+        let cached_rank = evaluator
+            .evaluate_with_board(&board, &hand)
+            .expect("Evaluation failed");
+
+        assert_eq!(rank, cached_rank);
+        assert_eq!(evaluator.len(), 1);
+        assert_eq!(cached_rank.description.as_ref().unwrap(), "9s Full of 3s");
+    }
+
+    #[test]
+    fn errors_are_not_cached() {
+        let mut evaluator = CachedEvaluator::new();
+
+        let duplicates = Card::vec_from_str("5h2hAhQh5h").unwrap();
+        assert_eq!(
+            evaluator.evaluate_hand(&duplicates),
+            Err(EvaluatorError::FailedToCalculateRank(
+                "Found duplicate cards".to_string()
+            ))
+        );
+
+        let not_enough_cards = Card::vec_from_str("5h2hAh").unwrap();
+        assert!(evaluator.evaluate_hand(&not_enough_cards).is_err());
+
+        assert!(evaluator.is_empty());
+    }
+}
@@ -1,20 +1,68 @@
 use num_traits::FromPrimitive;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 
 use itertools::Itertools;
 
 use crate::{
-    core::{Card, Value},
+    core::{card_mask, Card, Value},
     poker::ranks::{BadugiRank, BasicRank},
 };
 
 use super::EvaluatorError;
 
-fn choose(n: u64, k: u64) -> u64 {
-    if k == 0 {
-        return 1;
+/// Calculates the binomial coefficient nCk
+///
+/// Returns `None` if the calculation overflows a `u64`.
+pub(super) fn choose(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+
+    // C(n, i + 1) = C(n, i) * (n - i) / (i + 1), where each intermediate value is an integer
+    (0..k).try_fold(1u64, |acc, i| Some(acc.checked_mul(n - i)? / (i + 1)))
+}
+
+/// Counts the lesser of the number of distinct suits and the number of distinct ranks
+///
+/// This is an upper bound on the number of cards that can be played in a Badugi hand, and is
+/// exact when the cards are all distinct in rank and suit.
+fn distinct_rank_suit_count<'a>(cards: impl IntoIterator<Item = &'a Card>) -> usize {
+    let mut suit_bits = 0;
+    let mut rank_bits = 0;
+    for c in cards {
+        suit_bits |= (c.calculate_bit_pattern() >> 12) & 0xf;
+        rank_bits |= (c.calculate_bit_pattern() >> 16) & 0x1fff;
+    }
+    let mut distinct_rank_suit_cards = 0;
+
+    while suit_bits != 0 && rank_bits != 0 {
+        suit_bits &= suit_bits - 1;
+        rank_bits &= rank_bits - 1;
+
+        distinct_rank_suit_cards += 1;
     }
-    n * choose(n - 1, k - 1) / k
+
+    distinct_rank_suit_cards
+}
+
+/// Returns true if there are 4 cards that are all distinct in both rank and suit
+///
+/// Unlike `evaluate_hand()`, this accepts any number of cards and never errors. Less than 4 cards
+/// can never make a Badugi.
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::{core::Card, poker::evaluators::badugi_evaluator};
+///
+/// assert!(badugi_evaluator::is_badugi(&Card::vec_from_str("As2h3d4c").unwrap()));
+/// assert!(!badugi_evaluator::is_badugi(&Card::vec_from_str("As2s3d4c").unwrap()));
+/// ```
+pub fn is_badugi(cards: &[Card]) -> bool {
+    cards
+        .iter()
+        .combinations(4)
+        .any(|hand| distinct_rank_suit_count(hand) == 4)
 }
 
 /// Evaluates a Badugi hand
@@ -23,7 +71,7 @@ fn choose(n: u64, k: u64) -> u64 {
 ///
 /// This implementation does not support the use of duplicate cards. If duplicate cards are found,
 /// a `FailedToCalculateRank` error will return.
-pub fn evaluate_hand(player_hand: &Vec<Card>) -> Result<BadugiRank, EvaluatorError> {
+pub fn evaluate_hand(player_hand: &[Card]) -> Result<BadugiRank, EvaluatorError> {
     if player_hand.len() < 4 {
         return Err(EvaluatorError::NotEnoughCards {
             card_set_type: "Player hand".to_string(),
@@ -32,47 +80,30 @@ pub fn evaluate_hand(player_hand: &Vec<Card>) -> Result<BadugiRank, EvaluatorErr
         });
     }
 
-    if player_hand.len() != HashSet::<&Card>::from_iter(player_hand.iter()).len() {
+    if player_hand.len() != card_mask(player_hand).count_ones() as usize {
         return Err(EvaluatorError::FailedToCalculateRank(
             "Found duplicate cards".to_string(),
         ));
     }
 
-    let mut suit_bits = 0;
-    let mut rank_bits = 0;
-    for c in player_hand {
-        suit_bits |= (c.calculate_bit_pattern() >> 12) & 0xf;
-        rank_bits |= (c.calculate_bit_pattern() >> 16) & 0x1fff;
-    }
-    let mut best_hand_card_count = 0;
-
-    while suit_bits != 0 && rank_bits != 0 {
-        suit_bits &= suit_bits - 1;
-        rank_bits &= rank_bits - 1;
-
-        best_hand_card_count += 1;
-    }
+    // the distinct rank and suit count is only an upper bound (e.g. QdTcQs2c can only play 2
+    // cards), so the largest card count with a playable combination is searched for
+    let best_hand_card_count = (1..=distinct_rank_suit_count(player_hand))
+        .rev()
+        .find(|&card_count| {
+            player_hand
+                .iter()
+                .combinations(card_count)
+                .any(|canidate_hand| distinct_rank_suit_count(canidate_hand) == card_count)
+        })
+        .unwrap_or(0);
 
     player_hand
         .iter()
         .combinations(best_hand_card_count)
         .filter(|canidate_hand| {
-            let mut suit_bits = 0;
-            let mut rank_bits = 0;
-            for c in canidate_hand {
-                suit_bits |= (c.calculate_bit_pattern() >> 12) & 0xf;
-                rank_bits |= (c.calculate_bit_pattern() >> 16) & 0x1fff;
-            }
-            let mut distinct_rank_suit_cards = 0;
-
-            while suit_bits != 0 && rank_bits != 0 {
-                suit_bits &= suit_bits - 1;
-                rank_bits &= rank_bits - 1;
-
-                distinct_rank_suit_cards += 1;
-            }
-
-            !canidate_hand.is_empty() && distinct_rank_suit_cards == best_hand_card_count
+            !canidate_hand.is_empty()
+                && distinct_rank_suit_count(canidate_hand.iter().copied()) == best_hand_card_count
         })
         .map(|canidate_hand| {
             let card_ranks = canidate_hand
@@ -85,7 +116,7 @@ pub fn evaluate_hand(player_hand: &Vec<Card>) -> Result<BadugiRank, EvaluatorErr
             let card_count = card_ranks.len();
 
             for i in 1..card_count {
-                base_strength += choose(13, i as u64);
+                base_strength += choose(13, i as u64).expect("nCr overflowed for n = 13");
             }
 
             let (_, rank) = card_ranks.iter().enumerate().fold(
@@ -106,7 +137,7 @@ pub fn evaluate_hand(player_hand: &Vec<Card>) -> Result<BadugiRank, EvaluatorErr
                             (3, "3-card hand"),
                             (4, "Badugi"),
                         ]);
-                        let value_str: String = Value::from_u8((rank_strength - 1) % 13)
+                        let value_str: String = Value::from_u8((rank_strength + 12) % 13)
                             .map_or("".to_string(), |v| {
                                 format!("{}-high ", v.get_readable_string())
                             });
@@ -114,7 +145,8 @@ pub fn evaluate_hand(player_hand: &Vec<Card>) -> Result<BadugiRank, EvaluatorErr
                             Some(format!("{}{}", value_str, hand_name_mapping[&card_count]));
                     }
                     for s in (rank_strength + 1)..prev_rank_strength {
-                        let strength_inc = choose((s - 1) as u64, (card_count - i - 1) as u64);
+                        let strength_inc = choose(s as u64, (card_count - i - 1) as u64)
+                            .expect("nCr overflowed for n < 13");
                         acc.strength += strength_inc as u32;
                         acc.sub_rank += strength_inc as u16;
                     }
@@ -131,9 +163,101 @@ pub fn evaluate_hand(player_hand: &Vec<Card>) -> Result<BadugiRank, EvaluatorErr
         ))
 }
 
+/// Evaluates a Badugi hand made from a player's hole cards and community cards
+///
+/// This is used for homebrew variants where community cards are dealt. Similar to Omaha, the
+/// player must use exactly `hole_count` cards from their hand and the remaining `4 - hole_count`
+/// cards from the board. The best Badugi rank of all of these 4-card combinations is returned.
+///
+/// Returns a `BadugiRank`. If `hole_count` is greater than 4, then a `FailedToCalculateRank` error
+/// will return. If the player's hand contains less than `hole_count` cards or the board contains
+/// less than `4 - hole_count` cards, then a `NotEnoughCards` error will return.
+///
+/// This implementation does not support the use of duplicate cards. If duplicate cards are found
+/// when both the player's cards and the board are chained, a `FailedToCalculateRank` error will
+/// return.
+pub fn evaluate_with_board(
+    hole: &[Card],
+    board: &[Card],
+    hole_count: usize,
+) -> Result<BadugiRank, EvaluatorError> {
+    const HAND_SIZE: usize = 4;
+    if hole_count > HAND_SIZE {
+        return Err(EvaluatorError::FailedToCalculateRank(format!(
+            "Hole card count must be at most {}",
+            HAND_SIZE
+        )));
+    }
+
+    let board_count = HAND_SIZE - hole_count;
+    if hole.len() < hole_count {
+        return Err(EvaluatorError::NotEnoughCards {
+            card_set_type: "Player hand".to_string(),
+            expected_count: hole_count as u64,
+            actual_count: hole.len() as u64,
+        });
+    }
+
+    if board.len() < board_count {
+        return Err(EvaluatorError::NotEnoughCards {
+            card_set_type: "Board".to_string(),
+            expected_count: board_count as u64,
+            actual_count: board.len() as u64,
+        });
+    }
+
+    if hole.len() + board.len() != (card_mask(hole) | card_mask(board)).count_ones() as usize {
+        return Err(EvaluatorError::FailedToCalculateRank(
+            "Found duplicate cards".to_string(),
+        ));
+    }
+
+    hole.iter()
+        .cloned()
+        .combinations(hole_count)
+        .cartesian_product(
+            board
+                .iter()
+                .cloned()
+                .combinations(board_count)
+                .collect::<Vec<_>>(),
+        )
+        .map(|(mut hand, board)| {
+            hand.extend(board);
+            evaluate_hand(&hand)
+        })
+        .reduce(|acc, rank_res| {
+            let acc = acc?;
+            let rank = rank_res?;
+            Ok(std::cmp::max(rank, acc))
+        })
+        .unwrap_or(Err(EvaluatorError::FailedToCalculateRank(
+            "No hand combos were evaluated".to_string(),
+        )))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn choose_values_used_in_evaluation() {
+        let expected = [13, 78, 286, 715];
+        for (k, expected_value) in (1..=4).zip(expected) {
+            assert_eq!(Some(expected_value), choose(13, k), "Failed on 13C{}", k);
+        }
+        assert_eq!(Some(1), choose(13, 0));
+        assert_eq!(Some(0), choose(3, 4));
+    }
+
+    #[test]
+    fn choose_large_inputs() {
+        assert_eq!(None, choose(1000, 500));
+        assert_eq!(Some(u64::MAX), choose(u64::MAX, 1));
+        assert_eq!(Some(1), choose(u64::MAX, u64::MAX));
+        assert_eq!(Some(465_428_353_255_261_088), choose(62, 31));
+    }
 
     #[test]
     fn hand_all_same_suit() {
@@ -163,6 +287,20 @@ mod tests {
         assert_eq!(expected_rank, rank);
     }
 
+    #[test]
+    fn hand_all_aces() {
+        let hand = Card::vec_from_str("AhAsAdAc").expect("Cards did not parse correctly");
+        let rank = evaluate_hand(&hand).expect("Hand did not evaluate correctly");
+
+        let expected_rank = BadugiRank(BasicRank {
+            strength: 13,
+            hand_rank: 1,
+            sub_rank: 13,
+            description: Some("Ace-high 1-card hand".to_string()),
+        });
+        assert_eq!(expected_rank, rank);
+    }
+
     #[test]
     fn card_hand_size_2() {
         let hand = Card::vec_from_str("2h4hTd2d").expect("Cards did not parse correctly");
@@ -170,17 +308,31 @@ mod tests {
 
         // + 1 since all ranks start at strength of 1
         // +13 to account for all hand combos with only 1 card
-        // +63 for Σ nCr(n - 1, 1) for all n ∈ [4, 13)
-        // + 1 for Σ nCr(n - 1, 0) for all n ∈ [2, 3)
+        // +72 for Σ nCr(n, 1) for all n ∈ [4, 13)
+        // + 1 for Σ nCr(n, 0) for all n ∈ [2, 3)
         let expected_rank = BadugiRank(BasicRank {
-            strength: 1 + 13 + 63 + 1,
+            strength: 1 + 13 + 72 + 1,
             hand_rank: 2,
-            sub_rank: 65,
+            sub_rank: 74,
             description: Some("4-high 2-card hand".to_string()),
         });
         assert_eq!(rank, expected_rank);
     }
 
+    #[test]
+    fn card_hand_size_2_with_3_distinct_ranks_and_suits() {
+        // 3 distinct ranks and suits, but no 3 cards are distinct in both
+        let hand = Card::vec_from_str("QdTcQs2c").expect("Cards did not parse correctly");
+        let rank = evaluate_hand(&hand).expect("Hand did not evaluate correctly");
+
+        assert_eq!(rank.hand_rank, 2);
+        assert_eq!(rank.description.as_ref().unwrap(), "Queen-high 2-card hand");
+        assert_eq!(
+            rank,
+            evaluate_hand(&Card::vec_from_str("Qd2cQhQs").unwrap()).unwrap()
+        );
+    }
+
     #[test]
     fn card_hand_size_3() {
         let hand = Card::vec_from_str("3d7h6s7c").expect("Cards did not parse correctly");
@@ -188,13 +340,13 @@ mod tests {
 
         // +  1 since all ranks start at strength of 1
         // + 91 to account for all hand combos with only 1 or 2 cards
-        // +200 for Σ nCr(n - 1, 2) for all n ∈ [7, 13)
-        // +  0 for Σ nCr(n - 1, 1) for all n ∈ [6, 6) but |n| = 0
-        // +  2 for Σ nCr(n - 1, 0) for all n ∈ [3, 5)
+        // +251 for Σ nCr(n, 2) for all n ∈ [7, 13)
+        // +  0 for Σ nCr(n, 1) for all n ∈ [6, 6) but |n| = 0
+        // +  2 for Σ nCr(n, 0) for all n ∈ [3, 5)
         let expected_rank = BadugiRank(BasicRank {
-            strength: 1 + 91 + 200 + 2,
+            strength: 1 + 91 + 251 + 2,
             hand_rank: 3,
-            sub_rank: 203,
+            sub_rank: 254,
             description: Some("7-high 3-card hand".to_string()),
         });
         assert_eq!(expected_rank, rank)
@@ -207,14 +359,14 @@ mod tests {
 
         // +  1 since all ranks start at strength of 1
         // +377 to account for all hand combos with only 1-3 cards
-        // +  0 for Σ nCr(n - 1, 3) for all n ∈ [13, 13) but |n| = 0
-        // +161 for Σ nCr(n - 1, 2) for all n ∈ [5, 12)
-        // +  2 for Σ nCr(n - 1, 1) for all n ∈ [3, 4)
-        // +  1 for Σ nCr(n - 1, 0) for all n ∈ [1, 2)
+        // +  0 for Σ nCr(n, 3) for all n ∈ [13, 13) but |n| = 0
+        // +210 for Σ nCr(n, 2) for all n ∈ [5, 12)
+        // +  3 for Σ nCr(n, 1) for all n ∈ [3, 4)
+        // +  1 for Σ nCr(n, 0) for all n ∈ [1, 2)
         let expected_rank = BadugiRank(BasicRank {
-            strength: 1 + 377 + 161 + 2 + 1,
+            strength: 1 + 377 + 210 + 3 + 1,
             hand_rank: 4,
-            sub_rank: 165,
+            sub_rank: 215,
             description: Some("King-high Badugi".to_string()),
         });
         assert_eq!(expected_rank, rank);
@@ -227,14 +379,14 @@ mod tests {
 
         // +  1 since all ranks start at strength of 1
         // +377 to account for all hand combos with only 1-3 cards
-        // +490 for Σ nCr(n - 1, 3) for all n ∈ [6, 13)
-        // +  0 for Σ nCr(n - 1, 2) for all n ∈ [5, 5) but |n| = 0
-        // +  3 for Σ nCr(n - 1, 1) for all n ∈ [2, 4)
-        // +  0 for Σ nCr(n - 1, 0) for all n ∈ [1, 1) but |n| = 0
+        // +700 for Σ nCr(n, 3) for all n ∈ [6, 13)
+        // +  0 for Σ nCr(n, 2) for all n ∈ [5, 5) but |n| = 0
+        // +  5 for Σ nCr(n, 1) for all n ∈ [2, 4)
+        // +  0 for Σ nCr(n, 0) for all n ∈ [1, 1) but |n| = 0
         let expected_rank = BadugiRank(BasicRank {
-            strength: 1 + 377 + 490 + 3,
+            strength: 1 + 377 + 700 + 5,
             hand_rank: 4,
-            sub_rank: 494,
+            sub_rank: 706,
             description: Some("6-high Badugi".to_string()),
         });
         assert_eq!(expected_rank, rank);
@@ -247,14 +399,14 @@ mod tests {
 
         // +  1 since all ranks start at strength of 1
         // +377 to account for all hand combos with only 1-3 cards
-        // +495 for Σ nCr(n - 1, 3) for all n ∈ [4, 13)
-        // +  0 for Σ nCr(n - 1, 2) for all n ∈ [3, 3) but |n| = 0
-        // +  0 for Σ nCr(n - 1, 1) for all n ∈ [2, 2) but |n| = 0
-        // +  0 for Σ nCr(n - 1, 0) for all n ∈ [1, 1) but |n| = 0
+        // +714 for Σ nCr(n, 3) for all n ∈ [4, 13)
+        // +  0 for Σ nCr(n, 2) for all n ∈ [3, 3) but |n| = 0
+        // +  0 for Σ nCr(n, 1) for all n ∈ [2, 2) but |n| = 0
+        // +  0 for Σ nCr(n, 0) for all n ∈ [1, 1) but |n| = 0
         let expected_rank = BadugiRank(BasicRank {
-            strength: 1 + 377 + 495,
+            strength: 1 + 377 + 714,
             hand_rank: 4,
-            sub_rank: 496,
+            sub_rank: 715,
             description: Some("4-high Badugi".to_string()),
         });
         assert_eq!(expected_rank, rank);
@@ -267,14 +419,14 @@ mod tests {
 
         // +  1 since all ranks start at strength of 1
         // +377 to account for all hand combos with only 1-3 cards
-        // +495 for Σ nCr(n - 1, 3) for all n ∈ [4, 13)
-        // +  0 for Σ nCr(n - 1, 2) for all n ∈ [3, 3) but |n| = 0
-        // +  0 for Σ nCr(n - 1, 1) for all n ∈ [2, 2) but |n| = 0
-        // +  0 for Σ nCr(n - 1, 0) for all n ∈ [1, 1) but |n| = 0
+        // +714 for Σ nCr(n, 3) for all n ∈ [4, 13)
+        // +  0 for Σ nCr(n, 2) for all n ∈ [3, 3) but |n| = 0
+        // +  0 for Σ nCr(n, 1) for all n ∈ [2, 2) but |n| = 0
+        // +  0 for Σ nCr(n, 0) for all n ∈ [1, 1) but |n| = 0
         let expected_rank = BadugiRank(BasicRank {
-            strength: 1 + 377 + 495,
+            strength: 1 + 377 + 714,
             hand_rank: 4,
-            sub_rank: 496,
+            sub_rank: 715,
             description: Some("4-high Badugi".to_string()),
         });
         assert_eq!(expected_rank, rank);
@@ -287,17 +439,58 @@ mod tests {
 
         // +  1 since all ranks start at strength of 1
         // + 13 to account for all hand combos with only 1 card
-        // + 60 for Σ nCr(n - 1, 1) for all n ∈ [5, 13)
-        // +  0 for Σ nCr(n - 1, 0) for all n ∈ [4, 4) but |n| = 0
+        // + 68 for Σ nCr(n, 1) for all n ∈ [5, 13)
+        // +  0 for Σ nCr(n, 0) for all n ∈ [4, 4) but |n| = 0
         let expected_rank = BadugiRank(BasicRank {
-            strength: 1 + 13 + 60,
+            strength: 1 + 13 + 68,
             hand_rank: 2,
-            sub_rank: 61,
+            sub_rank: 69,
             description: Some("5-high 2-card hand".to_string()),
         });
         assert_eq!(expected_rank, rank);
     }
 
+    #[test]
+    fn all_badugi_strengths_are_distinct() {
+        let suits = "hcds";
+        let strengths: HashSet<u32> = (0..13u8)
+            .combinations(4)
+            .map(|values| {
+                let hand: Vec<Card> = values
+                    .iter()
+                    .zip(suits.chars())
+                    .map(|(&value, suit)| Card {
+                        value: Value::from_u8(value).unwrap(),
+                        suit: crate::core::Suit::from_char(suit).unwrap(),
+                    })
+                    .collect();
+                evaluate_hand(&hand)
+                    .expect("Hand did not evaluate correctly")
+                    .strength
+            })
+            .collect();
+
+        assert_eq!(strengths.len(), 715);
+        assert_eq!(strengths.iter().min(), Some(&(1 + 377)));
+        assert_eq!(strengths.iter().max(), Some(&(377 + 715)));
+    }
+
+    #[test]
+    fn is_badugi_four_cards() {
+        assert!(is_badugi(&Card::vec_from_str("Ks2h7dAc").unwrap()));
+        // suit conflict between the 2 and the 7
+        assert!(!is_badugi(&Card::vec_from_str("Ks2h7hAc").unwrap()));
+        // rank conflict between both kings
+        assert!(!is_badugi(&Card::vec_from_str("Ks2hKdAc").unwrap()));
+        assert!(!is_badugi(&Card::vec_from_str("Ks2h7d").unwrap()));
+    }
+
+    #[test]
+    fn is_badugi_from_5_cards() {
+        assert!(is_badugi(&Card::vec_from_str("Ks2h7hAc9d").unwrap()));
+        assert!(!is_badugi(&Card::vec_from_str("Ks2h7hAcAs").unwrap()));
+    }
+
     #[test]
     fn duplicate_cards() {
         let hand = Card::vec_from_str("3d3d3d3d").expect("Cards did not parse correctly");
@@ -326,4 +519,84 @@ mod tests {
 
         // note without this card gaurd, this test should still fail with FailedToCalculateRank
     }
+
+    #[test]
+    fn badugi_with_board_uses_exact_hole_count() {
+        let hole = Card::vec_from_str("AsKs2h3d").expect("Cards did not parse correctly");
+        let board = Card::vec_from_str("4c5hQcJd").expect("Cards did not parse correctly");
+
+        // A-3 from the hand and 4-5 from the board
+        let rank = evaluate_with_board(&hole, &board, 2).expect("Hand did not evaluate correctly");
+        assert_eq!(rank.description.as_ref().unwrap(), "5-high Badugi");
+
+        // without the constraint, A-2-3-4 would be the best Badugi
+        let unconstrained_rank = evaluate_hand(&[hole.clone(), board.clone()].concat())
+            .expect("Hand did not evaluate correctly");
+        assert_eq!(
+            unconstrained_rank.description.as_ref().unwrap(),
+            "4-high Badugi"
+        );
+        assert!(unconstrained_rank > rank);
+
+        let rank = evaluate_with_board(&hole, &board, 4).expect("Hand did not evaluate correctly");
+        assert_eq!(rank, evaluate_hand(&hole).unwrap());
+        assert_eq!(rank.description.as_ref().unwrap(), "3-high 3-card hand");
+
+        let rank = evaluate_with_board(&hole, &board, 0).expect("Hand did not evaluate correctly");
+        assert_eq!(rank, evaluate_hand(&board).unwrap());
+        assert_eq!(rank.description.as_ref().unwrap(), "Jack-high 3-card hand");
+    }
+
+    #[test]
+    fn badugi_with_board_best_combination() {
+        let hole = Card::vec_from_str("2s9dKhKc7h").expect("Cards did not parse correctly");
+        let board = Card::vec_from_str("3c8dAh6s4d").expect("Cards did not parse correctly");
+
+        // 2s from the hand and A-3-4 from the board
+        let rank = evaluate_with_board(&hole, &board, 1).expect("Hand did not evaluate correctly");
+
+        let best_rank = hole
+            .iter()
+            .combinations(1)
+            .cartesian_product(board.iter().combinations(3).collect::<Vec<_>>())
+            .map(|(hand, board)| {
+                let cards: Vec<Card> = hand.into_iter().chain(board).cloned().collect();
+                evaluate_hand(&cards).unwrap()
+            })
+            .max()
+            .unwrap();
+        assert_eq!(rank, best_rank);
+        assert_eq!(rank.description.as_ref().unwrap(), "4-high Badugi");
+    }
+
+    #[test]
+    fn badugi_with_board_errors() {
+        let hole = Card::vec_from_str("AsKs2h3d").expect("Cards did not parse correctly");
+        let board = Card::vec_from_str("4c5h").expect("Cards did not parse correctly");
+
+        assert_eq!(
+            evaluate_with_board(&hole, &board, 1).expect_err("Evaluated a 2 card board"),
+            EvaluatorError::NotEnoughCards {
+                card_set_type: "Board".to_string(),
+                expected_count: 3,
+                actual_count: 2,
+            }
+        );
+        assert_eq!(
+            evaluate_with_board(&hole[..1], &board, 2).expect_err("Evaluated a 1 card hand"),
+            EvaluatorError::NotEnoughCards {
+                card_set_type: "Player hand".to_string(),
+                expected_count: 2,
+                actual_count: 1,
+            }
+        );
+        assert_eq!(
+            evaluate_with_board(&hole, &board, 5).expect_err("Evaluated 5 hole cards"),
+            EvaluatorError::FailedToCalculateRank("Hole card count must be at most 4".to_string())
+        );
+        assert_eq!(
+            evaluate_with_board(&hole, &hole, 2).expect_err("Evaluated duplicate cards"),
+            EvaluatorError::FailedToCalculateRank("Found duplicate cards".to_string())
+        );
+    }
 }
@@ -16,8 +16,8 @@ use crate::poker::ranks::DramahaHighRank;
 /// when both the player's cards and the board are chained, a `FailedToCalculateRank` error will
 /// return.
 pub fn evaluate_hand(
-    player_hand: &Vec<Card>,
-    board: &Vec<Card>,
+    player_hand: &[Card],
+    board: &[Card],
 ) -> Result<DramahaHighRank, EvaluatorError> {
     const EXPECTED_PLAYER_CARD_COUNT: usize = 5;
     const MINIMUM_BOARD_CARDS: usize = 3;
@@ -1,5 +1,8 @@
+use itertools::Itertools;
 use num_traits::{One, PrimInt};
 
+use crate::core::{Card, CardCountError};
+
 fn pluralize<T: PrimInt + One>(n: T, base: &str, plural_suffix: &str) -> String {
     if n.is_one() {
         base.to_string()
@@ -8,6 +11,13 @@ fn pluralize<T: PrimInt + One>(n: T, base: &str, plural_suffix: &str) -> String
     }
 }
 
+fn card_string(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .map(|card| format!("{}{}", card.value.get_char(), card.suit.get_char()))
+        .join("")
+}
+
 /// An error wrapper that provides error handling for the evaluators
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq, thiserror::Error)]
@@ -38,4 +48,42 @@ pub enum EvaluatorError {
     #[non_exhaustive]
     #[error("Failed to calculate rank based off of set of cards: {0}")]
     FailedToCalculateRank(String),
+    /// The Cactus-Kev lookup tables do not contain an entry for a five-card combination
+    ///
+    /// This should never occur for a valid hand, so it can only be triggered by a corrupted input
+    /// or lookup table.
+    #[non_exhaustive]
+    #[error("Cactus-Kev lookup tables could not find a valid rank entry for {} (bit patterns: {bit_patterns:08x?}, hash index: {hash_index:?})", card_string(cards))]
+    LookupTableMiss {
+        /// The five-card combination that missed the lookup tables
+        cards: [Card; 5],
+        /// The Cactus-Kev bit pattern of each card in the combination
+        bit_patterns: [u32; 5],
+        /// The computed index into the hash table, or None if the combination was looked up in
+        /// the flush or unique rank tables instead
+        hash_index: Option<usize>,
+    },
+}
+
+impl From<CardCountError> for EvaluatorError {
+    fn from(err: CardCountError) -> Self {
+        match err {
+            CardCountError::NotEnoughCards {
+                expected_count,
+                actual_count,
+            } => Self::NotEnoughCards {
+                card_set_type: "Set of cards".to_string(),
+                expected_count: expected_count as u64,
+                actual_count: actual_count as u64,
+            },
+            CardCountError::TooManyCards {
+                expected_count,
+                actual_count,
+            } => Self::TooManyCards {
+                card_set_type: "Set of cards".to_string(),
+                expected_count: expected_count as u64,
+                actual_count: actual_count as u64,
+            },
+        }
+    }
 }
@@ -1,13 +1,16 @@
 use super::EvaluatorError;
+use itertools::Itertools;
 use num_traits::FromPrimitive;
 
-use crate::core::{Card, Value};
-use crate::poker::ranks::{BasicRank, HighRank};
+use crate::core::{card_mask, exactly_five, Card, Suit, Value};
+use crate::poker::ranks::{BasicRank, HandCategory, HighRank, StructuredHand};
 use crate::poker::tables;
-use std::collections::HashSet;
 use std::num::Wrapping;
 use std::ops::{Add, AddAssign, BitAnd, BitXor, BitXorAssign, Shl, Shr};
 
+const MINIMUM_CARD_COUNT: usize = 5;
+const MAXIMUM_CARD_COUNT: usize = 7;
+
 /// Evaluates the high hand for one player
 ///
 /// Returns a `HighRank`. If the total card count is not with the domain [5, 7], then either a
@@ -16,9 +19,12 @@ use std::ops::{Add, AddAssign, BitAnd, BitXor, BitXorAssign, Shl, Shr};
 ///
 /// This implementation does not support the use of duplicate cards. If duplicate cards are found,
 /// a `FailedToCalculateRank` error will return.
-pub fn evaluate_hand(cards: &Vec<Card>) -> Result<HighRank, EvaluatorError> {
-    const MINIMUM_CARD_COUNT: usize = 5;
-    const MAXIMUM_CARD_COUNT: usize = 7;
+///
+/// Every 5-card combination of the cards is looked up in the Cactus-Kev tables and the best rank
+/// found is returned, so a combination missing from the tables is ignored as long as another
+/// combination finds a rank. A `LookupTableMiss` only returns if every combination misses, in
+/// which case it reports the first combination (the first 5 cards given).
+pub fn evaluate_hand(cards: &[Card]) -> Result<HighRank, EvaluatorError> {
     let card_count = cards.len();
     if card_count < MINIMUM_CARD_COUNT {
         return Err(EvaluatorError::NotEnoughCards {
@@ -34,7 +40,7 @@ pub fn evaluate_hand(cards: &Vec<Card>) -> Result<HighRank, EvaluatorError> {
         });
     }
 
-    if card_count != HashSet::<&Card>::from_iter(cards.iter()).len() {
+    if card_count != card_mask(cards).count_ones() as usize {
         return Err(EvaluatorError::FailedToCalculateRank(
             "Found duplicate cards".to_string(),
         ));
@@ -66,42 +72,321 @@ pub fn evaluate_hand(cards: &Vec<Card>) -> Result<HighRank, EvaluatorError> {
         .flatten()
         .min()
     {
-        None => Err(EvaluatorError::FailedToCalculateRank(
-            "Cactus-Kev lookup tables could not find a valid rank entry".to_string(),
+        // every combination missed, so report the first combination that was looked up
+        None => Err(table_miss(
+            <[Card; 5]>::try_from(&cards[..MINIMUM_CARD_COUNT]).unwrap(),
         )),
-        Some(&best_rank) => {
-            let mut hand_rank: u16 = 0;
-            let mut sub_rank: u16 = 0;
-            if best_rank >= 1 {
-                let mut ranks_left = best_rank - 1;
-
-                // distinct combos from high card to straight flush
-                let strength_threshold = [1277, 2860, 858, 858, 10, 1277, 156, 156, 10];
-
-                for (i, &subranks) in strength_threshold.iter().enumerate().rev() {
-                    if ranks_left < subranks {
-                        hand_rank = (i + 1) as u16;
-                        sub_rank = subranks - ranks_left;
-                        break;
-                    }
-                    ranks_left -= subranks;
-                }
-            }
+        Some(&best_rank) => Ok(to_high_rank(best_rank)),
+    }
+}
 
-            let rank = HighRank(BasicRank {
-                strength: 7463 - best_rank as u32,
-                hand_rank,
-                sub_rank,
-                description: Some(
-                    get_string(hand_rank, sub_rank).unwrap_or_else(|err_str| err_str.to_string()),
-                ),
-            });
-            Ok(rank)
+/// Evaluates the high hand for one player from any source of cards
+///
+/// Behaves identically to `evaluate_hand()`, but accepts anything that can be iterated into cards
+/// (e.g. arrays, chained iterators of the player's hand and the board). The cards are collected
+/// into a fixed-size buffer on the stack rather than into a `Vec`, since a valid hand never
+/// contains more than 7 cards.
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::{core::Card, poker::evaluators::high_evaluator};
+///
+/// let hand = Card::vec_from_str("5h5s").unwrap();
+/// let board = Card::vec_from_str("2dTdKs5dAc").unwrap();
+///
+/// let rank = high_evaluator::evaluate_iter(hand.iter().chain(board.iter()).cloned()).unwrap();
+///
+/// assert_eq!(rank.description.as_ref().unwrap(), "Trip 5s");
+/// ```
+pub fn evaluate_iter<I: IntoIterator<Item = Card>>(cards: I) -> Result<HighRank, EvaluatorError> {
+    let mut buffer = [Card {
+        value: Value::Two,
+        suit: Suit::Heart,
+    }; MAXIMUM_CARD_COUNT];
+    let mut card_count = 0;
+
+    for card in cards {
+        if card_count < MAXIMUM_CARD_COUNT {
+            buffer[card_count] = card;
         }
+        card_count += 1;
+    }
+
+    if card_count > MAXIMUM_CARD_COUNT {
+        return Err(EvaluatorError::TooManyCards {
+            card_set_type: "Set of cards".to_string(),
+            expected_count: MAXIMUM_CARD_COUNT as u64,
+            actual_count: card_count as u64,
+        });
+    }
+
+    evaluate_hand(&buffer[..card_count])
+}
+
+/// Evaluates the high hand of exactly 5 cards
+///
+/// This skips enumerating hand combinations, making it a faster alternative to `evaluate_hand()`
+/// when the hand is known to be 5 cards (e.g. 5-card draw). If the card count is not exactly 5,
+/// then either a `NotEnoughCards` or a `TooManyCards` will return respective to whether not
+/// enough or too many cards were given.
+///
+/// This implementation does not support the use of duplicate cards. If duplicate cards are found,
+/// a `FailedToCalculateRank` error will return.
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::{core::Card, poker::evaluators::high_evaluator};
+///
+/// let hand = Card::vec_from_str("KhKdKc2s2h").unwrap();
+///
+/// let rank = high_evaluator::evaluate_five(&hand).unwrap();
+///
+/// assert_eq!(rank, high_evaluator::evaluate_hand(&hand).unwrap());
+/// ```
+pub fn evaluate_five(cards: &[Card]) -> Result<HighRank, EvaluatorError> {
+    let hand = exactly_five(cards)?;
+
+    if hand.len() != card_mask(&hand).count_ones() as usize {
+        return Err(EvaluatorError::FailedToCalculateRank(
+            "Found duplicate cards".to_string(),
+        ));
+    }
+
+    let [c0, c1, c2, c3, c4] = hand.map(|card| card.calculate_bit_pattern());
+    eval_five_cards(c0, c1, c2, c3, c4)
+        .map(to_high_rank)
+        .ok_or_else(|| table_miss(hand))
+}
+
+/// Evaluates the high hand for one player, returning every 5-card hand that makes the best rank
+///
+/// Validates the cards identically to `evaluate_hand()`. Along with the best `HighRank`, every
+/// 5-card subset of the cards that achieves that rank is returned in the order the combinations
+/// are enumerated. Multiple subsets are returned when the best hand can be made more than one way
+/// (e.g. a straight where one of its ranks is paired).
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::{core::Card, poker::evaluators::high_evaluator};
+///
+/// let cards = Card::vec_from_str("5h6d7c8s9h9d2c").unwrap();
+///
+/// let (rank, hands) = high_evaluator::evaluate_all_best(&cards).unwrap();
+///
+/// assert_eq!(rank.description.as_ref().unwrap(), "9 High Straight");
+/// assert_eq!(hands.len(), 2);
+/// ```
+pub fn evaluate_all_best(cards: &[Card]) -> Result<(HighRank, Vec<[Card; 5]>), EvaluatorError> {
+    let best_rank = evaluate_hand(cards)?;
+
+    let best_hands = cards
+        .iter()
+        .cloned()
+        .combinations(MINIMUM_CARD_COUNT)
+        .map(|hand| <[Card; 5]>::try_from(hand).unwrap())
+        .filter(|hand| {
+            let [c0, c1, c2, c3, c4] = hand.map(|card| card.calculate_bit_pattern());
+            eval_five_cards(c0, c1, c2, c3, c4)
+                .map(to_high_rank)
+                .as_ref()
+                == Some(&best_rank)
+        })
+        .collect();
+
+    Ok((best_rank, best_hands))
+}
+
+/// Evaluates the high hand for one player while leaving out a specific card
+///
+/// The best hand is made from all of the cards excluding `omit`, and the remaining cards are
+/// validated identically to `evaluate_hand()`. Comparing the result with the evaluation of all
+/// of the cards shows how much the hand relies on the omitted card. If `omit` is not within the
+/// cards, this is equivalent to `evaluate_hand()`.
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::{core::Card, poker::evaluators::high_evaluator};
+///
+/// let cards = Card::vec_from_str("QsQd2c7h9dKs").unwrap();
+/// let omit = Card::vec_from_str("Qs").unwrap()[0];
+///
+/// let rank = high_evaluator::evaluate_without(&cards, omit).unwrap();
+///
+/// assert_eq!(rank.description.as_ref().unwrap(), "King High");
+/// ```
+pub fn evaluate_without(cards: &[Card], omit: Card) -> Result<HighRank, EvaluatorError> {
+    evaluate_iter(cards.iter().filter(|&&card| card != omit).cloned())
+}
+
+/// Evaluates a partial high hand of 1 to 4 cards
+///
+/// This is typically used in games like Stud where only some of a player's cards are showing
+/// before showdown. Since there are not enough cards to make one, categories that require 5 cards
+/// (straights, flushes, full houses, and straight flushes) are never considered, so the returned
+/// `HighRank` will either be a high card, pair, two pair, trips, or quads. Missing cards are
+/// treated as being worse than any kicker (e.g. A-K-Q-J beats A-K-Q).
+///
+/// Please note that the strength of a partial rank is only comparable to the strengths of other
+/// partial ranks and should not be compared with ranks generated by `evaluate_hand()`. Partial
+/// strengths exceed 7462, so comparing the two with `HighRank`'s `Ord` gives meaningless results
+/// (e.g. a partial pair of Aces compares greater than a royal flush). For the same reason,
+/// `HighRank::hands_stronger()` and `HighRank::percentile()` return None for partial ranks, and
+/// `HighRank::from_sortable_bytes()` cannot decode them.
+///
+/// Returns a `HighRank`. If the total card count is not with the domain [1, 4], then either a
+/// `NotEnoughCards` or a `TooManyCards` will return respective to whether not enough or too many
+/// cards were given.
+///
+/// This implementation does not support the use of duplicate cards. If duplicate cards are found,
+/// a `FailedToCalculateRank` error will return.
+pub fn evaluate_partial(cards: &[Card]) -> Result<HighRank, EvaluatorError> {
+    const MINIMUM_PARTIAL_CARD_COUNT: usize = 1;
+    const MAXIMUM_PARTIAL_CARD_COUNT: usize = 4;
+    let card_count = cards.len();
+    if card_count < MINIMUM_PARTIAL_CARD_COUNT {
+        return Err(EvaluatorError::NotEnoughCards {
+            card_set_type: "Set of cards".to_string(),
+            expected_count: MINIMUM_PARTIAL_CARD_COUNT as u64,
+            actual_count: card_count as u64,
+        });
+    } else if card_count > MAXIMUM_PARTIAL_CARD_COUNT {
+        return Err(EvaluatorError::TooManyCards {
+            card_set_type: "Set of cards".to_string(),
+            expected_count: MAXIMUM_PARTIAL_CARD_COUNT as u64,
+            actual_count: card_count as u64,
+        });
     }
+
+    if card_count != card_mask(cards).count_ones() as usize {
+        return Err(EvaluatorError::FailedToCalculateRank(
+            "Found duplicate cards".to_string(),
+        ));
+    }
+
+    let mut value_counts = [0u8; 13];
+    for card in cards {
+        value_counts[card.value as usize] += 1;
+    }
+
+    // groups of (count, value) with the largest groups first, then by highest value
+    let mut groups: Vec<(u8, Value)> = value_counts
+        .iter()
+        .enumerate()
+        .filter(|(_, &count)| count > 0)
+        .map(|(value, &count)| (count, Value::from_usize(value).unwrap()))
+        .collect();
+    groups.sort_by(|a, b| b.cmp(a));
+
+    let (hand_rank, description) = match groups.as_slice() {
+        [(4, quad), ..] => (8, format!("Quad {}s", quad.get_readable_string())),
+        [(3, trip), ..] => (4, format!("Trip {}s", trip.get_readable_string())),
+        [(2, first_pair), (2, sec_pair), ..] => (
+            3,
+            format!(
+                "Two Pair of {}s and {}s",
+                first_pair.get_readable_string(),
+                sec_pair.get_readable_string()
+            ),
+        ),
+        [(2, pair), ..] => (2, format!("Pair of {}s", pair.get_readable_string())),
+        [(_, high), ..] => (1, format!("{} High", high.get_readable_string())),
+        [] => unreachable!("Partial hand contains at least one card"),
+    };
+
+    // Each group value is encoded as a base-14 digit, where 0 is reserved for a missing card
+    let sub_rank = groups
+        .iter()
+        .map(|&(_, value)| value as u16 + 1)
+        .chain(std::iter::repeat(0))
+        .take(MAXIMUM_PARTIAL_CARD_COUNT)
+        .fold(0, |acc, digit| acc * 14 + digit);
+
+    Ok(HighRank(BasicRank {
+        strength: hand_rank as u32 * 14u32.pow(MAXIMUM_PARTIAL_CARD_COUNT as u32) + sub_rank as u32,
+        hand_rank,
+        sub_rank,
+        description: Some(description),
+    }))
+}
+
+/// Evaluates the high hand for one player and breaks down the values that make up the hand
+///
+/// Returns a `StructuredHand` containing the `HighRank` of the hand along with its category, the
+/// values defining the category (e.g. the trips and pair of a full house), and the kickers. This
+/// is useful for rendering hands without parsing the rank description. Errors are identical to
+/// those returned by `evaluate_hand()`.
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::{core::{Card, Value}, poker::{evaluators::high_evaluator, ranks::HandCategory}};
+///
+/// let cards = Card::vec_from_str("KhKsQdQc2s9h3d").unwrap();
+///
+/// let hand = high_evaluator::evaluate_structured(&cards).unwrap();
+///
+/// assert_eq!(hand.category, HandCategory::TwoPair);
+/// assert_eq!(hand.primary_values, vec![Value::King, Value::Queen]);
+/// assert_eq!(hand.kickers, vec![Value::Nine]);
+/// ```
+pub fn evaluate_structured(cards: &[Card]) -> Result<StructuredHand, EvaluatorError> {
+    let rank = evaluate_hand(cards)?;
+    let best_rank = (7463 - rank.strength) as u16;
+
+    let best_hand = cards
+        .iter()
+        .cloned()
+        .combinations(MINIMUM_CARD_COUNT)
+        .find(|hand| {
+            let [c0, c1, c2, c3, c4] = <[Card; 5]>::try_from(hand.as_slice())
+                .unwrap()
+                .map(|card| card.calculate_bit_pattern());
+            eval_five_cards(c0, c1, c2, c3, c4) == Some(best_rank)
+        })
+        .ok_or(EvaluatorError::FailedToCalculateRank(
+            "No 5-card hand matched the best rank".to_string(),
+        ))?;
+
+    let category = HandCategory::from_u16(rank.hand_rank).ok_or(
+        EvaluatorError::FailedToCalculateRank("Hand rank was not a valid category".to_string()),
+    )?;
+
+    // groups of (count, value) with the largest groups first, then by highest value
+    let groups: Vec<(usize, Value)> = best_hand
+        .iter()
+        .map(|card| card.value)
+        .counts()
+        .into_iter()
+        .map(|(value, count)| (count, value))
+        .sorted_by(|a, b| b.cmp(a))
+        .collect();
+    let values: Vec<Value> = groups.iter().map(|&(_, value)| value).collect();
+
+    let (primary_values, kickers) = match category {
+        HandCategory::Straight | HandCategory::StraightFlush => {
+            let is_wheel = values.contains(&Value::Ace)
+                && values.contains(&Value::Five)
+                && !values.contains(&Value::King);
+            let high_value = if is_wheel { Value::Five } else { values[0] };
+            (vec![high_value], vec![])
+        }
+        HandCategory::Flush => (values, vec![]),
+        HandCategory::HighCard | HandCategory::Pair | HandCategory::Trips | HandCategory::Quads => {
+            (values[..1].to_vec(), values[1..].to_vec())
+        }
+        HandCategory::TwoPair | HandCategory::FullHouse => {
+            (values[..2].to_vec(), values[2..].to_vec())
+        }
+    };
+
+    Ok(StructuredHand {
+        rank,
+        category,
+        primary_values,
+        kickers,
+    })
 }
 
-fn get_string(hand_rank: u16, sub_rank: u16) -> Result<String, &'static str> {
+pub(crate) fn get_string(hand_rank: u16, sub_rank: u16) -> Result<String, &'static str> {
     let hand_category;
     match hand_rank {
         1 => {
@@ -286,6 +571,55 @@ fn get_string(hand_rank: u16, sub_rank: u16) -> Result<String, &'static str> {
     }
 }
 
+pub(crate) fn to_high_rank(best_rank: u16) -> HighRank {
+    let mut hand_rank: u16 = 0;
+    let mut sub_rank: u16 = 0;
+    if best_rank >= 1 {
+        let mut ranks_left = best_rank - 1;
+
+        // distinct combos from high card to straight flush
+        let strength_threshold = [1277, 2860, 858, 858, 10, 1277, 156, 156, 10];
+
+        for (i, &subranks) in strength_threshold.iter().enumerate().rev() {
+            if ranks_left < subranks {
+                hand_rank = (i + 1) as u16;
+                sub_rank = subranks - ranks_left;
+                break;
+            }
+            ranks_left -= subranks;
+        }
+    }
+
+    HighRank(BasicRank {
+        strength: 7463 - best_rank as u32,
+        hand_rank,
+        sub_rank,
+        description: Some(
+            get_string(hand_rank, sub_rank).unwrap_or_else(|err_str| err_str.to_string()),
+        ),
+    })
+}
+
+/// Creates a `LookupTableMiss` error containing the diagnostic data of a five-card combination
+fn table_miss(hand: [Card; 5]) -> EvaluatorError {
+    let bit_patterns = hand.map(|card| card.calculate_bit_pattern());
+    let [c0, c1, c2, c3, c4] = bit_patterns;
+    let q = (c0 | c1 | c2 | c3 | c4) >> 16;
+
+    let hash_index = if c0 & c1 & c2 & c3 & c4 & 0xf000 != 0 || tables::UNIQUE5.contains_key(&q) {
+        None
+    } else {
+        let q = (c0 & 0xff) * (c1 & 0xff) * (c2 & 0xff) * (c3 & 0xff) * (c4 & 0xff);
+        Some(find_fast(Wrapping(q)))
+    };
+
+    EvaluatorError::LookupTableMiss {
+        cards: hand,
+        bit_patterns,
+        hash_index,
+    }
+}
+
 fn eval_five_cards(c0: u32, c1: u32, c2: u32, c3: u32, c4: u32) -> Option<u16> {
     let q = (c0 | c1 | c2 | c3 | c4) >> 16;
 
@@ -401,7 +735,7 @@ mod tests {
             let player_hand = Card::vec_from_str(h).unwrap();
 
             let player_rank = evaluate_hand(&player_hand)
-                .expect(&format!("Evaluation failed for hand {:?}", player_hand));
+                .unwrap_or_else(|_| panic!("Evaluation failed for hand {:?}", player_hand));
 
             let string_rank = player_rank
                 .description
@@ -556,6 +890,126 @@ mod tests {
         }
     }
 
+    #[test]
+    fn evaluate_chained_hand_and_board() {
+        let hand = Card::vec_from_str("AhKh").unwrap();
+        let board = Card::vec_from_str("QhJhTh2c3d").unwrap();
+
+        let rank =
+            evaluate_iter(hand.iter().chain(board.iter()).cloned()).expect("Evaluation failed");
+
+        let mut all_cards = hand.clone();
+        all_cards.extend(board.iter());
+
+        assert_eq!(evaluate_hand(&all_cards).expect("Evaluation failed"), rank);
+        assert_eq!(9, rank.hand_rank);
+        assert_eq!(10, rank.sub_rank);
+    }
+
+    #[test]
+    fn evaluate_iter_too_many_cards() {
+        let cards = Card::vec_from_str("AhKhQhJhTh2c3d4s").unwrap();
+
+        let err = evaluate_iter(cards).expect_err("Evaluated more than 7 cards");
+
+        assert_eq!(
+            err,
+            EvaluatorError::TooManyCards {
+                card_set_type: "Set of cards".to_string(),
+                expected_count: 7,
+                actual_count: 8,
+            }
+        );
+    }
+
+    #[test]
+    fn partial_pair_four_cards() {
+        let cards = Card::vec_from_str("KsKd7h2c").unwrap();
+
+        let rank = evaluate_partial(&cards).expect("Evaluation failed");
+
+        assert_eq!(2, rank.hand_rank);
+        assert_eq!(rank.description.as_ref().unwrap(), "Pair of Kings");
+
+        let weaker_kicker =
+            evaluate_partial(&Card::vec_from_str("KhKc6h2d").unwrap()).expect("Evaluation failed");
+        let ace_high =
+            evaluate_partial(&Card::vec_from_str("AsQd7h2c").unwrap()).expect("Evaluation failed");
+
+        assert!(rank > weaker_kicker);
+        assert!(rank > ace_high);
+    }
+
+    #[test]
+    fn partial_lone_ace() {
+        let cards = Card::vec_from_str("Ah").unwrap();
+
+        let rank = evaluate_partial(&cards).expect("Evaluation failed");
+
+        assert_eq!(1, rank.hand_rank);
+        assert_eq!(rank.description.as_ref().unwrap(), "Ace High");
+
+        let king_high =
+            evaluate_partial(&Card::vec_from_str("Kh").unwrap()).expect("Evaluation failed");
+
+        assert!(rank > king_high);
+    }
+
+    #[test]
+    fn partial_card_count_bounds() {
+        assert_eq!(
+            evaluate_partial(&[]).expect_err("Evaluated an empty hand"),
+            EvaluatorError::NotEnoughCards {
+                card_set_type: "Set of cards".to_string(),
+                expected_count: 1,
+                actual_count: 0,
+            }
+        );
+        assert_eq!(
+            evaluate_partial(&Card::vec_from_str("AhKhQhJhTh").unwrap())
+                .expect_err("Evaluated a 5-card hand"),
+            EvaluatorError::TooManyCards {
+                card_set_type: "Set of cards".to_string(),
+                expected_count: 4,
+                actual_count: 5,
+            }
+        );
+    }
+
+    #[test]
+    fn structured_two_pair() {
+        let cards = Card::vec_from_str("AsAd8h8c5s4h3d").unwrap();
+
+        let hand = evaluate_structured(&cards).expect("Evaluation failed");
+
+        assert_eq!(hand.category, HandCategory::TwoPair);
+        assert_eq!(hand.primary_values, vec![Value::Ace, Value::Eight]);
+        assert_eq!(hand.kickers, vec![Value::Five]);
+        assert_eq!(hand.rank, evaluate_hand(&cards).expect("Evaluation failed"));
+    }
+
+    #[test]
+    fn structured_full_house() {
+        let cards = Card::vec_from_str("9s9d9hKcKs2h").unwrap();
+
+        let hand = evaluate_structured(&cards).expect("Evaluation failed");
+
+        assert_eq!(hand.category, HandCategory::FullHouse);
+        assert_eq!(hand.primary_values, vec![Value::Nine, Value::King]);
+        assert!(hand.kickers.is_empty());
+        assert_eq!(hand.rank.description.as_ref().unwrap(), "9s Full of Kings");
+    }
+
+    #[test]
+    fn structured_wheel_straight() {
+        let cards = Card::vec_from_str("As2c3c4d5hKh").unwrap();
+
+        let hand = evaluate_structured(&cards).expect("Evaluation failed");
+
+        assert_eq!(hand.category, HandCategory::Straight);
+        assert_eq!(hand.primary_values, vec![Value::Five]);
+    }
+
     #[test]
     fn duplicate_cards_two_pair() {
         let player_hand = Card::vec_from_str("5h2dAdAs5h").unwrap();
@@ -583,28 +1037,219 @@ mod tests {
 
         // If the duplicate gaurd did not exist, then the evaluator would output the following
         // error:
-        // assert_eq!(player_rank, EvaluatorError::FailedToCalculateRank("Cactus-Kev lookup tables could not find a valid rank entry".to_string()));
+        // assert!(matches!(player_rank, EvaluatorError::LookupTableMiss { .. }));
+    }
+
+    #[test]
+    fn evaluate_five_card_counts() {
+        let cards = Card::vec_from_str("AhKhQhJhTh9h").unwrap();
+
+        assert_eq!(
+            evaluate_five(&cards[..4]).expect_err("Evaluated 4 cards"),
+            EvaluatorError::NotEnoughCards {
+                card_set_type: "Set of cards".to_string(),
+                expected_count: 5,
+                actual_count: 4,
+            }
+        );
+        assert_eq!(
+            evaluate_five(&cards),
+            Err(EvaluatorError::TooManyCards {
+                card_set_type: "Set of cards".to_string(),
+                expected_count: 5,
+                actual_count: 6,
+            })
+        );
+
+        let rank = evaluate_five(&cards[..5]).expect("Evaluation failed");
+        assert_eq!(rank, evaluate_hand(&cards[..5]).unwrap());
+        assert_eq!(
+            rank.description.as_ref().unwrap(),
+            "Ace High Straight Flush"
+        );
+    }
+
+    #[test]
+    fn evaluate_five_duplicate_cards() {
+        let cards = Card::vec_from_str("AhKhQhAhTh").unwrap();
+
+        assert_eq!(
+            evaluate_five(&cards),
+            Err(EvaluatorError::FailedToCalculateRank(
+                "Found duplicate cards".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn evaluate_all_best_paired_straight() {
+        let cards = Card::vec_from_str("5h6d7c8s9h9d2c").unwrap();
+
+        let (rank, hands) = evaluate_all_best(&cards).expect("Evaluation failed");
+
+        assert_eq!(rank, evaluate_hand(&cards).unwrap());
+        assert_eq!(rank.description.as_ref().unwrap(), "9 High Straight");
+        assert_eq!(
+            hands,
+            vec![
+                <[Card; 5]>::try_from(Card::vec_from_str("5h6d7c8s9h").unwrap()).unwrap(),
+                <[Card; 5]>::try_from(Card::vec_from_str("5h6d7c8s9d").unwrap()).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn evaluate_all_best_unique_hand() {
+        let cards = Card::vec_from_str("AhKhQhJhTh2c3d").unwrap();
+
+        let (rank, hands) = evaluate_all_best(&cards).expect("Evaluation failed");
+
+        assert_eq!(
+            rank.description.as_ref().unwrap(),
+            "Ace High Straight Flush"
+        );
+        assert_eq!(hands.len(), 1);
+    }
+
+    #[test]
+    fn evaluate_without_flush_card() {
+        let cards = Card::vec_from_str("AhKh9h5h2hQd3c").unwrap();
+        let omit = Card::vec_from_str("2h").unwrap()[0];
+
+        let full_rank = evaluate_hand(&cards).expect("Evaluation failed");
+        let rank = evaluate_without(&cards, omit).expect("Evaluation failed");
+
+        assert_eq!(full_rank.description.as_ref().unwrap(), "Ace High Flush");
+        assert_eq!(rank.description.as_ref().unwrap(), "Ace High");
+        assert!(full_rank > rank);
+
+        let missing_card = Card::vec_from_str("2d").unwrap()[0];
+        assert_eq!(evaluate_without(&cards, missing_card), Ok(full_rank));
+    }
+
+    #[test]
+    fn evaluate_without_too_few_cards() {
+        let cards = Card::vec_from_str("AhKh9h5h2h").unwrap();
+
+        assert_eq!(
+            evaluate_without(&cards, cards[0]).expect_err("Evaluated 4 cards"),
+            EvaluatorError::NotEnoughCards {
+                card_set_type: "Set of cards".to_string(),
+                expected_count: 5,
+                actual_count: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn table_miss_diagnostics() {
+        // five copies of the same card can never be dealt, so the flush table has no entry
+        let ace_of_spades = Card::vec_from_str("As").unwrap()[0];
+        let hand = [ace_of_spades; 5];
+        let bit_pattern = ace_of_spades.calculate_bit_pattern();
+
+        assert_eq!(
+            eval_five_cards(
+                bit_pattern,
+                bit_pattern,
+                bit_pattern,
+                bit_pattern,
+                bit_pattern
+            ),
+            None
+        );
+
+        let err = table_miss(hand);
+        assert_eq!(
+            err,
+            EvaluatorError::LookupTableMiss {
+                cards: hand,
+                bit_patterns: [bit_pattern; 5],
+                hash_index: None,
+            }
+        );
+        assert!(err.to_string().contains("AsAsAsAsAs"));
+        assert!(err.to_string().contains(&format!("{:08x}", bit_pattern)));
+
+        // a valid hand without a flush or 5 unique ranks computes a hash table index
+        let pair = <[Card; 5]>::try_from(Card::vec_from_str("AsAd2c3h4s").unwrap()).unwrap();
+        assert!(matches!(
+            table_miss(pair),
+            EvaluatorError::LookupTableMiss {
+                hash_index: Some(_),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn all_straight_flushes() {
+        use strum::IntoEnumIterator;
+
+        for suit in Suit::iter() {
+            // the wheel (5-high) has a sub rank of 1 and the royal flush (Ace-high) has 10
+            for sub_rank in 1..=10u16 {
+                let low_value = sub_rank as u8 + 11;
+                let hand: Vec<Card> = (low_value..low_value + 5)
+                    .map(|v| Card {
+                        value: Value::from_u8(v % 13).unwrap(),
+                        suit,
+                    })
+                    .collect();
+
+                let rank = evaluate_hand(&hand).expect("Evaluation failed");
+
+                assert_eq!(rank.hand_rank, 9, "{:?} was not a straight flush", hand);
+                assert_eq!(rank.sub_rank, sub_rank, "{:?} had the wrong sub rank", hand);
+                assert_eq!(rank.strength, 7452 + sub_rank as u32);
+            }
+        }
     }
 }
 
 #[cfg(all(feature = "unstable", test))]
 mod bench {
     use super::*;
-    use test::Bencher;
+    use crate::core::{seed_from_u32, CardDeck};
+    use test::{black_box, Bencher};
 
     #[bench]
     fn bench_same_rank_different_cards(b: &mut Bencher) {
-        b.iter(|| {
-            let player1_hand = Card::vec_from_str("2s3s4s5s7s").unwrap();
-            let player2_hand = Card::vec_from_str("2h3h4h5h7h").unwrap();
+        let player1_hand = Card::vec_from_str("2s3s4s5s7s").unwrap();
+        let player2_hand = Card::vec_from_str("2h3h4h5h7h").unwrap();
 
-            let player1_rank = evaluate_hand(&player1_hand).expect("Evaluation failed");
-            let player2_rank = evaluate_hand(&player2_hand).expect("Evaluation failed");
+        let player1_rank = evaluate_hand(&player1_hand).expect("Evaluation failed");
+        let player2_rank = evaluate_hand(&player2_hand).expect("Evaluation failed");
 
-            assert_eq!(6, player1_rank.rank_strength >> 12);
-            assert_eq!(1, player1_rank.rank_strength & 0xFFF);
+        assert_eq!(6, player1_rank.hand_rank);
+        assert_eq!(1, player1_rank.sub_rank);
+        assert_eq!(player1_rank, player2_rank);
 
-            assert_eq!(player1_rank, player2_rank);
+        b.iter(|| {
+            black_box(evaluate_hand(black_box(&player1_hand)).unwrap());
+            black_box(evaluate_hand(black_box(&player2_hand)).unwrap());
+        })
+    }
+
+    #[bench]
+    fn bench_random_seven_card_hands(b: &mut Bencher) {
+        let hands: Vec<Vec<Card>> = (0..1000u32)
+            .map(|i| {
+                CardDeck::new(Some(seed_from_u32(i)))
+                    .expect("Failed to create deck")
+                    .deal_cards(7, false)
+                    .expect("Failed to deal cards")
+            })
+            .collect();
+
+        for hand in &hands {
+            assert!(evaluate_hand(hand).is_ok());
+        }
+
+        b.iter(|| {
+            for hand in black_box(&hands) {
+                black_box(evaluate_hand(hand).unwrap());
+            }
         })
     }
 }
@@ -8,13 +8,19 @@ use crate::{core::Card, poker::ranks::Low27Rank};
 /// `NotEnoughCards` or a `TooManyCards` will return respective to whether not enough or too many
 /// cards were given.
 ///
+/// Hands without a pair are described by listing every card from highest to lowest (e.g.
+/// "8-6-4-3-2"), while all other hands keep their usual names (see
+/// `Low27Rank::regenerate_description()`).
+///
 /// This implementation does not support the use of duplicate cards. If duplicate cards are found,
 /// a `FailedToCalculateRank` error will return.
-pub fn evaluate_hand(cards: &Vec<Card>) -> Result<Low27Rank, EvaluatorError> {
+pub fn evaluate_hand(cards: &[Card]) -> Result<Low27Rank, EvaluatorError> {
     high_evaluator::evaluate_hand(cards).map(|high_rank| {
         let mut rank = (*high_rank).clone();
         rank.strength = 7463 - rank.strength;
-        Low27Rank(rank)
+        let mut rank = Low27Rank(rank);
+        rank.regenerate_description();
+        rank
     })
 }
 
@@ -73,14 +79,14 @@ mod tests {
                 .description
                 .as_ref()
                 .expect("Player 1 hand generated bad rank"),
-            "9 High"
+            "9-7-5-3-2"
         );
         assert_eq!(
             player2_rank
                 .description
                 .as_ref()
                 .expect("Player 2 hand generated bad rank"),
-            "8 High"
+            "8-6-4-3-2"
         );
         assert!(player1_rank < player2_rank);
     }
@@ -98,17 +104,72 @@ mod tests {
                 .description
                 .as_ref()
                 .expect("Player 1 hand generated bad rank"),
-            "8 High"
+            "8-7-5-3-2"
         );
         assert_eq!(
             player2_rank
                 .description
                 .as_ref()
                 .expect("Player 2 hand generated bad rank"),
-            "8 High"
+            "8-6-4-3-2"
         );
         assert!(player1_rank < player2_rank);
     }
+
+    #[test]
+    fn string_no_pair_lowball() {
+        let hands = vec![
+            ("2c3d4h5s7c", "7-5-4-3-2"),
+            ("2c3d4h6s7c", "7-6-4-3-2"),
+            ("AcKdQhJs9c", "A-K-Q-J-9"),
+        ];
+        for (h, expected_str) in hands {
+            let player_hand = Card::vec_from_str(h).unwrap();
+
+            let player_rank = evaluate_hand(&player_hand).expect("Evaluation failed");
+
+            let string_rank = player_rank
+                .description
+                .as_ref()
+                .expect("Hand generated bad rank");
+            assert_eq!(expected_str, string_rank, "\nFailed on hand {}\n", h);
+        }
+    }
+
+    #[test]
+    fn all_no_pair_descriptions_match_cards() {
+        use crate::core::{Suit, Value};
+        use itertools::Itertools;
+        use strum::IntoEnumIterator;
+
+        let suits = [
+            Suit::Heart,
+            Suit::Club,
+            Suit::Heart,
+            Suit::Heart,
+            Suit::Heart,
+        ];
+        let mut count = 0;
+        for values in Value::iter().rev().combinations(5) {
+            let hand: Vec<Card> = values
+                .iter()
+                .zip(suits)
+                .map(|(&value, suit)| Card { value, suit })
+                .collect();
+
+            let rank = evaluate_hand(&hand).expect("Evaluation failed");
+            if rank.hand_rank != 1 {
+                continue;
+            }
+            count += 1;
+
+            let expected_str = values.iter().map(|value| value.get_char()).join("-");
+            assert_eq!(rank.description.as_ref().unwrap(), &expected_str);
+        }
+
+        assert_eq!(count, 1277);
+    }
+
     #[test]
     fn string_pairs_two_pairs_highs() {
         let hands = vec![
@@ -118,13 +179,13 @@ mod tests {
             ("7c7hAcKsJs", "Pair of 7s"),
             ("2c2hAcQsQd", "Two Pair of Queens and 2s"),
             ("2c7hAcQcQs", "Pair of Queens"),
-            ("2c7hTcKsQs", "King High"),
+            ("2c7hTcKsQs", "K-Q-T-7-2"),
         ];
         for (h, expected_str) in hands {
             let player_hand = Card::vec_from_str(h).unwrap();
 
             let player_rank = evaluate_hand(&player_hand)
-                .expect(&format!("Evaluation failed for hand {:?}", player_hand));
+                .unwrap_or_else(|_| panic!("Evaluation failed for hand {:?}", player_hand));
 
             let string_rank = player_rank
                 .description
@@ -283,21 +344,29 @@ mod tests {
 #[cfg(all(feature = "unstable", test))]
 mod bench {
     use super::*;
-    use test::Bencher;
+    use test::{black_box, Bencher};
 
     #[bench]
     fn bench_same_rank_different_cards(b: &mut Bencher) {
-        b.iter(|| {
-            let player1_hand = Card::vec_from_str("2s3s4s5s7s").unwrap();
-            let player2_hand = Card::vec_from_str("2h3h4h5h7h").unwrap();
+        let player1_hand = Card::vec_from_str("2s3s4s5s7s").unwrap();
+        let player2_hand = Card::vec_from_str("2h3h4h5h7h").unwrap();
 
-            let player1_rank = evaluate_hand(&player1_hand).expect("Evaluation failed");
-            let player2_rank = evaluate_hand(&player2_hand).expect("Evaluation failed");
+        let player1_rank = evaluate_hand(&player1_hand).expect("Evaluation failed");
+        let player2_rank = evaluate_hand(&player2_hand).expect("Evaluation failed");
 
-            assert_eq!(6, player1_rank.get_rank_strength() >> 12);
-            assert_eq!(1, player1_rank.get_rank_strength() & 0xFFF);
+        assert_eq!(player1_rank, player2_rank);
 
-            assert_eq!(player1_rank, player2_rank);
+        b.iter(|| {
+            black_box(evaluate_hand(black_box(&player1_hand)).unwrap());
+            black_box(evaluate_hand(black_box(&player2_hand)).unwrap());
         })
     }
+
+    #[bench]
+    fn bench_ace_high_no_pair(b: &mut Bencher) {
+        // the worst no pair hand for lowball describes the last sub rank
+        let player_hand = Card::vec_from_str("AcKdQhJs9c").unwrap();
+
+        b.iter(|| black_box(evaluate_hand(black_box(&player_hand)).unwrap()))
+    }
 }
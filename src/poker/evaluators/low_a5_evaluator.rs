@@ -1 +1,295 @@
+use itertools::Itertools;
+use num_traits::FromPrimitive;
 
+use super::{badugi_evaluator::choose, EvaluatorError};
+
+use crate::{
+    core::{card_mask, Card, Value},
+    poker::ranks::{BasicRank, RazzRank},
+};
+
+// distinct combos from no pair to quads, best to worst
+const CATEGORY_COUNTS: [(u16, u16); 6] = [
+    (1, 1287), // no pair
+    (2, 2860), // pair
+    (3, 858),  // two pair
+    (4, 858),  // trips
+    (7, 156),  // full house
+    (8, 156),  // quads
+];
+
+/// Evaluates the Ace-to-5 low hand for one player
+///
+/// Returns a `RazzRank`. If the total card count is not with the domain [5, 7], then either a
+/// `NotEnoughCards` or a `TooManyCards` will return respective to whether not enough or too many
+/// cards were given.
+///
+/// Aces always play low and straights and flushes do not count against the hand. There is no
+/// qualifier, so every hand will produce a rank. The `strength` of the rank ranges from 1 (King
+/// quads with a Queen) to 6175 (5-4-3-2-A), where a higher strength is a better low hand. The
+/// `hand_rank` follows the same numbering as the high evaluator (1 for no pair, 2 for a pair, 3
+/// for two pair, 4 for trips, 7 for a full house, and 8 for quads) and the `sub_rank` is the
+/// strength of the hand within its `hand_rank`.
+///
+/// This implementation does not support the use of duplicate cards. If duplicate cards are found,
+/// a `FailedToCalculateRank` error will return.
+pub fn evaluate_hand(cards: &[Card]) -> Result<RazzRank, EvaluatorError> {
+    const MINIMUM_CARD_COUNT: usize = 5;
+    const MAXIMUM_CARD_COUNT: usize = 7;
+    let card_count = cards.len();
+    if card_count < MINIMUM_CARD_COUNT {
+        return Err(EvaluatorError::NotEnoughCards {
+            card_set_type: "Set of cards".to_string(),
+            expected_count: MINIMUM_CARD_COUNT as u64,
+            actual_count: card_count as u64,
+        });
+    } else if card_count > MAXIMUM_CARD_COUNT {
+        return Err(EvaluatorError::TooManyCards {
+            card_set_type: "Set of cards".to_string(),
+            expected_count: MAXIMUM_CARD_COUNT as u64,
+            actual_count: card_count as u64,
+        });
+    }
+
+    if card_count != card_mask(cards).count_ones() as usize {
+        return Err(EvaluatorError::FailedToCalculateRank(
+            "Found duplicate cards".to_string(),
+        ));
+    }
+
+    cards
+        .iter()
+        .combinations(MINIMUM_CARD_COUNT)
+        .map(|hand| eval_five_cards(&hand))
+        .max()
+        .ok_or(EvaluatorError::FailedToCalculateRank(
+            "No hand combos were evaluated".to_string(),
+        ))
+}
+
+/// Converts a card value to its ace-low rank (Ace = 0, 2 = 1, ..., King = 12)
+fn ace_low_rank(value: Value) -> u16 {
+    (value as u16 + 1) % 13
+}
+
+fn ace_low_value(rank: u16) -> Value {
+    Value::from_u16((rank + 12) % 13).unwrap()
+}
+
+/// Calculates the colexicographic index of a set of distinct ranks sorted in ascending order
+///
+/// Comparing the highest rank first, lower indices have lower ranks. `skipped` ranks are removed
+/// from the domain before indexing so that kickers of paired hands are densely packed.
+fn colex_index(ranks: &[u16], skipped: &[u16]) -> u16 {
+    ranks
+        .iter()
+        .map(|&r| r - skipped.iter().filter(|&&s| s < r).count() as u16)
+        .enumerate()
+        .map(|(i, r)| choose(r as u64, i as u64 + 1).unwrap() as u16)
+        .sum()
+}
+
+fn eval_five_cards(hand: &[&Card]) -> RazzRank {
+    let mut rank_counts = [0u8; 13];
+    for card in hand {
+        rank_counts[ace_low_rank(card.value) as usize] += 1;
+    }
+
+    let ranks_with_count = |count: u8| -> Vec<u16> {
+        (0..13)
+            .filter(|&r| rank_counts[r as usize] == count)
+            .collect()
+    };
+    let singles = ranks_with_count(1);
+    let pairs = ranks_with_count(2);
+    let trips = ranks_with_count(3);
+    let quads = ranks_with_count(4);
+
+    let readable = |rank: u16| ace_low_value(rank).get_readable_string() + "s";
+
+    // index within the category, where 0 is the best hand of the category
+    let (category, index, description) = if let [quad] = quads[..] {
+        (
+            5,
+            quad * 12 + colex_index(&singles, &[quad]),
+            format!("Quad {}", readable(quad)),
+        )
+    } else if let ([trip], [pair]) = (&trips[..], &pairs[..]) {
+        (
+            4,
+            trip * 12 + colex_index(&[*pair], &[*trip]),
+            format!("{} Full of {}", readable(*trip), readable(*pair)),
+        )
+    } else if let [trip] = trips[..] {
+        (
+            3,
+            trip * 66 + colex_index(&singles, &[trip]),
+            format!("Trip {}", readable(trip)),
+        )
+    } else if let [low_pair, high_pair] = pairs[..] {
+        (
+            2,
+            colex_index(&pairs, &[]) * 11 + colex_index(&singles, &pairs),
+            format!(
+                "Two Pair of {} and {}",
+                readable(high_pair),
+                readable(low_pair)
+            ),
+        )
+    } else if let [pair] = pairs[..] {
+        (
+            1,
+            pair * 220 + colex_index(&singles, &[pair]),
+            format!("Pair of {}", readable(pair)),
+        )
+    } else {
+        (
+            0,
+            colex_index(&singles, &[]),
+            singles
+                .iter()
+                .rev()
+                .map(|&r| ace_low_value(r).get_char().to_string())
+                .join("-"),
+        )
+    };
+
+    let worse_hands: u16 = CATEGORY_COUNTS[category + 1..]
+        .iter()
+        .map(|&(_, count)| count)
+        .sum();
+    let (hand_rank, category_count) = CATEGORY_COUNTS[category];
+    let sub_rank = category_count - index;
+
+    RazzRank(BasicRank {
+        strength: (worse_hands + sub_rank) as u32,
+        hand_rank,
+        sub_rank,
+        description: Some(description),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn wheel_is_best_hand() {
+        let hand = Card::vec_from_str("As2d3c4h5s").unwrap();
+        let rank = evaluate_hand(&hand).expect("Evaluation failed");
+
+        assert_eq!(rank.strength, 6175);
+        assert_eq!(rank.hand_rank, 1);
+        assert_eq!(rank.sub_rank, 1287);
+        assert_eq!(rank.description.as_ref().unwrap(), "5-4-3-2-A");
+    }
+
+    #[test]
+    fn worst_hand() {
+        let hand = Card::vec_from_str("KsKdKcKhQs").unwrap();
+        let rank = evaluate_hand(&hand).expect("Evaluation failed");
+
+        assert_eq!(rank.strength, 1);
+        assert_eq!(rank.hand_rank, 8);
+        assert_eq!(rank.sub_rank, 1);
+        assert_eq!(rank.description.as_ref().unwrap(), "Quad Kings");
+    }
+
+    #[test]
+    fn straights_and_flushes_do_not_count() {
+        let flush = Card::vec_from_str("2h3h4h5h7h").unwrap();
+        let rank = evaluate_hand(&flush).expect("Evaluation failed");
+
+        assert_eq!(rank.description.as_ref().unwrap(), "7-5-4-3-2");
+
+        let straight = Card::vec_from_str("2h3d4c5s6h").unwrap();
+        let rank = evaluate_hand(&straight).expect("Evaluation failed");
+
+        assert_eq!(rank.description.as_ref().unwrap(), "6-5-4-3-2");
+    }
+
+    #[test]
+    fn best_low_from_seven_cards() {
+        let hand = Card::vec_from_str("KsAd2c2h8s5d3c").unwrap();
+        let rank = evaluate_hand(&hand).expect("Evaluation failed");
+
+        assert_eq!(rank.description.as_ref().unwrap(), "8-5-3-2-A");
+    }
+
+    #[test]
+    fn category_ordering() {
+        let hands = [
+            "As2d3c4h5s", // 5-4-3-2-A
+            "As2d3c4h6s", // 6-4-3-2-A
+            "9s8d7c6h5s", // 9-8-7-6-5
+            "KsQdJcTh9s", // K-Q-J-10-9
+            "AsAd2c3h4s", // Pair of Aces
+            "As2d3c4h4s", // Pair of 4s
+            "KsKdQcJhTs", // Pair of Kings
+            "AsAd2c2h3s", // Two Pair of 2s and Aces
+            "2s2d3c3hAs", // Two Pair of 3s and 2s
+            "AsAdAc2h3s", // Trip Aces
+            "KsKdKcQhJs", // Trip Kings
+            "AsAdAc2h2s", // Aces Full of 2s
+            "KsKdKcQhQs", // Kings Full of Queens
+            "AsAdAcAh2s", // Quad Aces
+            "KsKdKcKhQs", // Quad Kings
+        ];
+        let ranks: Vec<RazzRank> = hands
+            .iter()
+            .map(|h| evaluate_hand(&Card::vec_from_str(h).unwrap()).expect("Evaluation failed"))
+            .collect();
+
+        for (i, pair) in ranks.windows(2).enumerate() {
+            assert!(
+                pair[0] > pair[1],
+                "{} should be a better low than {}",
+                hands[i],
+                hands[i + 1]
+            );
+        }
+    }
+
+    #[test]
+    fn all_strengths_are_distinct() {
+        let values: Vec<Value> = (0..13).map(|v| Value::from_u8(v).unwrap()).collect();
+        let suits = "hcds";
+        let mut strengths = HashSet::new();
+
+        // every multiset of 5 values with at most 4 of each value
+        for combo in values.iter().combinations_with_replacement(5) {
+            if combo.iter().counts().values().any(|&count| count > 4) {
+                continue;
+            }
+            let mut seen = [0usize; 13];
+            let hand: Vec<Card> = combo
+                .iter()
+                .map(|&&value| {
+                    let suit = suits.chars().nth(seen[value as usize]).unwrap();
+                    seen[value as usize] += 1;
+                    Card {
+                        value,
+                        suit: crate::core::Suit::from_char(suit).unwrap(),
+                    }
+                })
+                .collect();
+
+            let rank = evaluate_hand(&hand).expect("Evaluation failed");
+            assert!(rank.strength >= 1 && rank.strength <= 6175);
+            strengths.insert(rank.strength);
+        }
+
+        assert_eq!(strengths.len(), 6175);
+    }
+
+    #[test]
+    fn duplicate_cards() {
+        let hand = Card::vec_from_str("As2d3c4hAs").unwrap();
+
+        assert_eq!(
+            evaluate_hand(&hand).expect_err("Evaluated duplicate cards"),
+            EvaluatorError::FailedToCalculateRank("Found duplicate cards".to_string())
+        );
+    }
+}
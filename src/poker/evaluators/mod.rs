@@ -2,6 +2,23 @@
 //!
 //! Please note that all evaluators will error if the appropriate number of cards are given or if
 //! any duplicate cards are detected.
+//!
+//! Each evaluator's `evaluate_hand()` is also re-exported at the root of this module under a
+//! shorter name (e.g. `evaluate_high` for `high_evaluator::evaluate_hand`).
+//!
+//! ```rust
+//! use playing_cards::core::Card;
+//! use playing_cards::poker::evaluators::{evaluate_high, evaluate_low_27, evaluate_omaha_hi};
+//!
+//! let hand = Card::vec_from_str("2c3d4h5s7c").unwrap();
+//! assert_eq!(evaluate_high(&hand).unwrap().description.as_ref().unwrap(), "7 High");
+//! assert!(evaluate_low_27(&hand).is_ok());
+//!
+//! let omaha_hand = Card::vec_from_str("AsKsQdJd").unwrap();
+//! let board = Card::vec_from_str("Ts9s2s").unwrap();
+//! let rank = evaluate_omaha_hi(&omaha_hand, &board).unwrap();
+//! assert_eq!(rank.description.as_ref().unwrap(), "Ace High Flush");
+//! ```
 
 mod evaluator_errors;
 pub use self::evaluator_errors::EvaluatorError;
@@ -71,7 +88,7 @@ pub mod high_evaluator;
 ///
 /// let rank = low_27_evaluator::evaluate_hand(&hand).unwrap();
 ///
-/// assert_eq!(rank.description.as_ref().unwrap(), "Ace High");
+/// assert_eq!(rank.description.as_ref().unwrap(), "A-T-5-3-2");
 /// ```
 ///
 /// ```rust
@@ -93,14 +110,45 @@ pub mod high_evaluator;
 /// let hero_rank = low_27_evaluator::evaluate_hand(&hero_hand).unwrap();
 /// let villan_rank = low_27_evaluator::evaluate_hand(&villan_hand).unwrap();
 ///
-/// assert_eq!(hero_rank.description.as_ref().unwrap(), "10 High");
+/// assert_eq!(hero_rank.description.as_ref().unwrap(), "T-7-6-3-2");
 /// assert_eq!(villan_rank.description.as_ref().unwrap(), "6 High Straight");
 ///
 /// assert!(hero_rank > villan_rank); // Hero's hand is better than the villan's
 /// ```
 pub mod low_27_evaluator;
 
-// pub mod low_a5_evaluator;
+/// An evaluator for Ace-to-5 lowball hands
+///
+/// This evaluator is typically used for games like Razz and California Lowball. Aces always play
+/// low, and straights and flushes do not count against the hand, making 5-4-3-2-A the best
+/// possible hand.
+///
+/// ## Examples
+/// ```rust
+/// use playing_cards::{core::Card, poker::evaluators::low_a5_evaluator};
+///
+/// let hand = Card::vec_from_str("As2s3s4s5s").unwrap();
+///
+/// let rank = low_a5_evaluator::evaluate_hand(&hand).unwrap();
+///
+/// assert_eq!(rank.description.as_ref().unwrap(), "5-4-3-2-A");
+/// ```
+///
+/// ```rust
+/// use playing_cards::{core::Card, poker::evaluators::low_a5_evaluator};
+///
+/// let hero_hand = Card::vec_from_str("7c5d3h2s8dKsKd").unwrap();
+/// let villan_hand = Card::vec_from_str("6h4c3s2c9h9sQd").unwrap();
+///
+/// let hero_rank = low_a5_evaluator::evaluate_hand(&hero_hand).unwrap();
+/// let villan_rank = low_a5_evaluator::evaluate_hand(&villan_hand).unwrap();
+///
+/// assert_eq!(hero_rank.description.as_ref().unwrap(), "8-7-5-3-2");
+/// assert_eq!(villan_rank.description.as_ref().unwrap(), "9-6-4-3-2");
+///
+/// assert!(hero_rank > villan_rank); // Hero's hand is better than the villan's
+/// ```
+pub mod low_a5_evaluator;
 
 /// An evaluator for Omaha High hands
 ///
@@ -195,7 +243,8 @@ pub mod omaha_hi_evaluator;
 /// ```
 ///
 /// ```rust
-/// use playing_cards::{core::Card, poker::evaluators::omaha_hilo_evaluator};
+/// use std::cmp::Ordering;
+/// use playing_cards::{core::Card, poker::{evaluators::omaha_hilo_evaluator, ranks::compare_lo}};
 ///
 /// let hero_hand = Card::vec_from_str("5s6c9s7c").unwrap();
 /// let villan_hand = Card::vec_from_str("AhKdAsTh").unwrap();
@@ -211,7 +260,8 @@ pub mod omaha_hi_evaluator;
 /// assert_eq!(villan_ranks.lo_rank, None);
 ///
 /// assert!(hero_ranks.hi_rank > villan_ranks.hi_rank); // Hero's hi hand is better than the villan's
-/// assert!(hero_ranks.lo_rank.gt(&villan_ranks.lo_rank)); // Hero's lo hand is better than the villan's
+/// // Hero's lo hand is better than the villan's
+/// assert_eq!(compare_lo(&hero_ranks.lo_rank, &villan_ranks.lo_rank), Ordering::Greater);
 /// ```
 pub mod omaha_hilo_evaluator;
 
@@ -312,3 +362,40 @@ pub mod dramaha_high_evaluator;
 /// assert!(hero_rank > villan_rank);
 /// ```
 pub mod badugi_evaluator;
+
+/// An evaluator for short deck (6+ Hold'em) high hands
+///
+/// Short deck is played with a 36-card deck where all 2s through 5s are removed. Due to the
+/// change in probabilities, a flush beats a full house and trips beat a straight.
+///
+/// ## Examples
+/// ```rust
+/// use playing_cards::{core::Card, poker::evaluators::short_deck_evaluator};
+///
+/// let hero_hand = Card::vec_from_str("AhTh").unwrap();
+/// let villan_hand = Card::vec_from_str("KsKd").unwrap();
+/// let board = Card::vec_from_str("Kh7h9hJc6s").unwrap();
+///
+/// let mut hero_cards = hero_hand.clone();
+/// hero_cards.extend(board.iter());
+/// let mut villan_cards = villan_hand.clone();
+/// villan_cards.extend(board.iter());
+///
+/// let hero_rank = short_deck_evaluator::evaluate_hand(&hero_cards).unwrap();
+/// let villan_rank = short_deck_evaluator::evaluate_hand(&villan_cards).unwrap();
+///
+/// assert_eq!(hero_rank.description.as_ref().unwrap(), "Ace High Flush");
+/// assert_eq!(villan_rank.description.as_ref().unwrap(), "Trip Kings");
+///
+/// assert!(hero_rank > villan_rank); // Hero's hand is better than the villan's
+/// ```
+pub mod short_deck_evaluator;
+
+pub use self::badugi_evaluator::evaluate_hand as evaluate_badugi;
+pub use self::dramaha_high_evaluator::evaluate_hand as evaluate_dramaha_high;
+pub use self::high_evaluator::evaluate_hand as evaluate_high;
+pub use self::low_27_evaluator::evaluate_hand as evaluate_low_27;
+pub use self::low_a5_evaluator::evaluate_hand as evaluate_low_a5;
+pub use self::omaha_hi_evaluator::evaluate_hand as evaluate_omaha_hi;
+pub use self::omaha_hilo_evaluator::evaluate_hand as evaluate_omaha_hilo;
+pub use self::short_deck_evaluator::evaluate_hand as evaluate_short_deck;
@@ -9,17 +9,23 @@ use crate::poker::ranks::HighRank;
 /// Evaluates the Omaha high hand for one player
 ///
 /// Returns a `HighRank`. If the player's hand contains less than 4 cards or the board contains
-/// less than 3 cards, then a `NotEnoughCards` error will return.
+/// less than 3 cards, then a `NotEnoughCards` error will return. If the board contains more than
+/// 5 cards, then a `TooManyCards` error will return.
 ///
 /// This implementation does not support the use of duplicate cards. If duplicate cards are found
 /// when both the player's cards and the board are chained, a `FailedToCalculateRank` error will
 /// return.
-pub fn evaluate_hand(
-    player_hand: &Vec<Card>,
-    board: &Vec<Card>,
-) -> Result<HighRank, EvaluatorError> {
-    const MINIMUM_PLAYER_CARDS: usize = 4;
-    const MINIMUM_BOARD_CARDS: usize = 3;
+pub fn evaluate_hand(player_hand: &[Card], board: &[Card]) -> Result<HighRank, EvaluatorError> {
+    check_player_hand(player_hand)?;
+
+    OmahaBoard::new(board)?.evaluate_hole(player_hand)
+}
+
+const MINIMUM_PLAYER_CARDS: usize = 4;
+const MINIMUM_BOARD_CARDS: usize = 3;
+const MAXIMUM_BOARD_CARDS: usize = 5;
+
+fn check_player_hand(player_hand: &[Card]) -> Result<(), EvaluatorError> {
     if player_hand.len() < MINIMUM_PLAYER_CARDS {
         return Err(EvaluatorError::NotEnoughCards {
             card_set_type: "Player hand".to_string(),
@@ -28,42 +34,132 @@ pub fn evaluate_hand(
         });
     }
 
-    if board.len() < MINIMUM_BOARD_CARDS {
-        // 3 because it allows for evaluation on flop-only flop-turn-only boards
-        return Err(EvaluatorError::NotEnoughCards {
-            card_set_type: "Board".to_string(),
-            expected_count: MINIMUM_BOARD_CARDS as u64,
-            actual_count: board.len() as u64,
-        });
-    }
+    Ok(())
+}
 
-    let hand_combinations: Vec<Vec<Card>> = player_hand.iter().cloned().combinations(2).collect();
-    let board_combinations: Vec<Vec<Card>> = board.iter().cloned().combinations(3).collect();
+/// An Omaha board with its 3-card combinations precomputed
+///
+/// Evaluating a player's hand in Omaha requires combining every pair of the player's cards with
+/// every 3-card combination of the board. When evaluating multiple players against the same board
+/// (e.g. at showdown), an `OmahaBoard` only computes the board combinations once.
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::{core::Card, poker::evaluators::omaha_hi_evaluator::OmahaBoard};
+///
+/// let board = OmahaBoard::new(&Card::vec_from_str("KhQsJdKdJs").unwrap()).unwrap();
+///
+/// let hero_rank = board.evaluate_hole(&Card::vec_from_str("AsKc9d7h").unwrap()).unwrap();
+/// let villain_rank = board.evaluate_hole(&Card::vec_from_str("AhTc2d2h").unwrap()).unwrap();
+///
+/// assert_eq!(hero_rank.description.as_ref().unwrap(), "Trip Kings");
+/// assert_eq!(villain_rank.description.as_ref().unwrap(), "Ace High Straight");
+/// ```
+#[derive(Debug, Clone)]
+pub struct OmahaBoard {
+    board_combinations: Vec<Vec<Card>>,
+}
 
-    let best_rank = hand_combinations
-        .iter()
-        .cartesian_product(board_combinations.iter())
-        .map(|(hand, board)| {
-            let mut all_cards = hand.clone();
-            all_cards.extend(board.iter());
-            high_evaluator::evaluate_hand(&all_cards)
+impl OmahaBoard {
+    /// Creates a new `OmahaBoard` from the board cards
+    ///
+    /// If the board contains less than 3 cards, then a `NotEnoughCards` error will return. If the
+    /// board contains more than 5 cards, then a `TooManyCards` error will return.
+    pub fn new(board: &[Card]) -> Result<Self, EvaluatorError> {
+        if board.len() < MINIMUM_BOARD_CARDS {
+            // 3 because it allows for evaluation on flop-only flop-turn-only boards
+            return Err(EvaluatorError::NotEnoughCards {
+                card_set_type: "Board".to_string(),
+                expected_count: MINIMUM_BOARD_CARDS as u64,
+                actual_count: board.len() as u64,
+            });
+        } else if board.len() > MAXIMUM_BOARD_CARDS {
+            return Err(EvaluatorError::TooManyCards {
+                card_set_type: "Board".to_string(),
+                expected_count: MAXIMUM_BOARD_CARDS as u64,
+                actual_count: board.len() as u64,
+            });
+        }
+
+        Ok(Self {
+            board_combinations: board.iter().cloned().combinations(3).collect(),
         })
-        .reduce(|acc, rank_res| {
-            let acc = acc?;
-            let rank = rank_res?;
-            Ok(std::cmp::max(rank, acc))
-        })
-        .unwrap_or(Err(EvaluatorError::FailedToCalculateRank(
-            "No hand combos were evaluated".to_string(),
-        )))?;
+    }
 
-    Ok(best_rank)
+    /// Evaluates the Omaha high hand for one player against the board
+    ///
+    /// Returns the same `HighRank` as `evaluate_hand()` would for the player's hand and the
+    /// board. If the player's hand contains less than 4 cards, then a `NotEnoughCards` error will
+    /// return. If duplicate cards are found when both the player's cards and the board are
+    /// chained, a `FailedToCalculateRank` error will return.
+    pub fn evaluate_hole(&self, hole: &[Card]) -> Result<HighRank, EvaluatorError> {
+        check_player_hand(hole)?;
+
+        let hand_combinations: Vec<Vec<Card>> = hole.iter().cloned().combinations(2).collect();
+
+        hand_combinations
+            .iter()
+            .cartesian_product(self.board_combinations.iter())
+            .map(|(hand, board)| {
+                let mut all_cards = hand.clone();
+                all_cards.extend(board.iter());
+                high_evaluator::evaluate_hand(&all_cards)
+            })
+            .reduce(|acc, rank_res| {
+                let acc = acc?;
+                let rank = rank_res?;
+                Ok(std::cmp::max(rank, acc))
+            })
+            .unwrap_or(Err(EvaluatorError::FailedToCalculateRank(
+                "No hand combos were evaluated".to_string(),
+            )))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn omaha_board_matches_evaluate_hand() {
+        let board_cards = Card::vec_from_str("KhQsJdKdJs").unwrap();
+        let board = OmahaBoard::new(&board_cards).expect("Board could not be created");
+
+        for hole in ["AsKc9d7h", "AhTc2d2h", "QhQd3c4c", "9s8s7s6s", "Ks2h3h4h"] {
+            let hole = Card::vec_from_str(hole).unwrap();
+
+            // every 2 hole cards with every 3 board cards without any precomputation
+            let brute_force_rank = hole
+                .iter()
+                .combinations(2)
+                .cartesian_product(board_cards.iter().combinations(3))
+                .map(|(hand, board)| {
+                    let cards: Vec<Card> = hand.into_iter().chain(board).cloned().collect();
+                    high_evaluator::evaluate_hand(&cards).expect("Evaluation failed")
+                })
+                .max();
+
+            let rank = board.evaluate_hole(&hole).expect("Evaluation failed");
+            assert_eq!(Some(&rank), brute_force_rank.as_ref());
+            assert_eq!(
+                Ok(rank),
+                evaluate_hand(&hole, &board_cards),
+                "Ranks differ for hole cards {:?}",
+                hole
+            );
+        }
+
+        let hole = Card::vec_from_str("AsKc9d").unwrap();
+        assert_eq!(
+            board.evaluate_hole(&hole),
+            Err(EvaluatorError::NotEnoughCards {
+                card_set_type: "Player hand".to_string(),
+                expected_count: 4,
+                actual_count: 3,
+            })
+        );
+    }
+
     #[test]
     fn trips_omaha() {
         let player_hand = Card::vec_from_str("AsKc9d7h").unwrap();
@@ -107,21 +203,42 @@ mod tests {
 
         // If the duplicate guard did not exist in high_evaluator::evaluate_hand, then the evaluator
         // would output the following error:
-        // assert_eq!(player_rank, EvaluatorError::FailedToCalculateRank("Cactus-Kev lookup tables could not find a valid rank entry".to_string()));
+        // assert!(matches!(player_rank, EvaluatorError::LookupTableMiss { .. }));
+    }
+
+    #[test]
+    fn board_card_count_bounds() {
+        let player_hand = Card::vec_from_str("AsKc9d7h").unwrap();
+        let board = Card::vec_from_str("KhQsJdKdJs2c").unwrap();
+
+        assert_eq!(
+            evaluate_hand(&player_hand, &board).expect_err("Evaluated a 6 card board"),
+            EvaluatorError::TooManyCards {
+                card_set_type: "Board".to_string(),
+                expected_count: 5,
+                actual_count: 6,
+            }
+        );
+
+        let player_rank = evaluate_hand(&player_hand, &board[..5]).expect("Evaluation failed");
+        assert_eq!(player_rank.description.as_ref().unwrap(), "Trip Kings");
     }
 }
 
 #[cfg(all(feature = "unstable", test))]
 mod bench {
     use super::*;
-    use test::Bencher;
+    use test::{black_box, Bencher};
 
     #[bench]
     fn omaha_high_hands(b: &mut Bencher) {
         let player_hand = Card::vec_from_str("2s3c4h7cJhKs").unwrap();
         let board = Card::vec_from_str("5h6dAsTdTh").unwrap();
+
+        assert!(evaluate_hand(&player_hand, &board).is_ok());
+
         b.iter(|| {
-            let _player1_rank = evaluate_hand(&player_hand, &board).expect("Evaluation failed");
+            black_box(evaluate_hand(black_box(&player_hand), black_box(&board)).unwrap());
         })
     }
 }
@@ -1,4 +1,7 @@
-use std::collections::HashSet;
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
 
 use super::{omaha_hi_evaluator, EvaluatorError};
 
@@ -6,8 +9,8 @@ use itertools::Itertools;
 use phf::phf_map;
 
 use crate::{
-    core::{Card, Value},
-    poker::ranks::{BasicRank, LowA5Rank, OmahaHiLoRank},
+    core::{card_mask, BitDeck, Card, CardDeck, Value},
+    poker::ranks::{rank_field, BasicRank, LowA5Rank, OmahaHiLoRank},
 };
 
 /// Evaluates the Omaha hi/lo hand for one player
@@ -19,8 +22,8 @@ use crate::{
 /// when both the player's cards and the board are chained, a `FailedToCalculateRank` error will
 /// return.
 pub fn evaluate_hand(
-    player_hand: &Vec<Card>,
-    board: &Vec<Card>,
+    player_hand: &[Card],
+    board: &[Card],
 ) -> Result<OmahaHiLoRank, EvaluatorError> {
     const MINIMUM_PLAYER_CARDS: usize = 4;
     const MINIMUM_BOARD_CARDS: usize = 3;
@@ -44,8 +47,17 @@ pub fn evaluate_hand(
     }
 
     let hi_hand = omaha_hi_evaluator::evaluate_hand(player_hand, board)?;
-    let mut lo_hand: Option<LowA5Rank> = None;
+    let lo_hand = best_lo_hand(player_hand, board);
+
+    Ok(OmahaHiLoRank {
+        hi_rank: hi_hand,
+        lo_rank: lo_hand,
+    })
+}
 
+/// Finds the best qualifying lo hand using exactly 2 cards from the player's hand and 3 cards from
+/// the board
+fn best_lo_hand(player_hand: &[Card], board: &[Card]) -> Option<LowA5Rank> {
     let player_hand_sub_8: Vec<Card> = player_hand
         .iter()
         .filter(|card| card.value <= Value::Eight || card.value == Value::Ace)
@@ -58,46 +70,173 @@ pub fn evaluate_hand(
         .cloned()
         .collect();
 
-    if player_hand_sub_8.len() >= 2 && board_sub_8.len() >= 3 {
-        let hand_combinations: Vec<Vec<Card>> =
-            player_hand_sub_8.iter().cloned().combinations(2).collect();
-        let board_combinations: Vec<Vec<Card>> =
-            board_sub_8.iter().cloned().combinations(3).collect();
+    if player_hand_sub_8.len() < 2 || board_sub_8.len() < 3 {
+        return None;
+    }
 
-        lo_hand = hand_combinations
+    let hand_combinations: Vec<Vec<Card>> =
+        player_hand_sub_8.iter().cloned().combinations(2).collect();
+    let board_combinations: Vec<Vec<Card>> = board_sub_8.iter().cloned().combinations(3).collect();
+
+    hand_combinations
+        .iter()
+        .cartesian_product(board_combinations.iter())
+        .map(|(hand_combo, board_combo)| {
+            let cards = [
+                hand_combo[0],
+                hand_combo[1],
+                board_combo[0],
+                board_combo[1],
+                board_combo[2],
+            ];
+            if card_mask(&cards).count_ones() != 5 {
+                return None;
+            }
+
+            let bit_strength = cards
+                .iter()
+                .fold(0, |acc, card| acc | (1 << ((card.value as u8 + 1) % 13)));
+
+            if let Some(&(strength, hand_rank, sub_rank, desc)) = LO_8_MAP.get(&bit_strength) {
+                Some(LowA5Rank(BasicRank {
+                    strength,
+                    hand_rank,
+                    sub_rank,
+                    description: Some(desc.to_string()),
+                }))
+            } else {
+                None
+            }
+        })
+        .fold(None, |acc, rank| if acc < rank { rank } else { acc })
+}
+
+/// Finds the best possible qualifying lo hand (the "low nuts") for a board
+///
+/// Every pair of hole cards that could be dealt from the cards not on the board is considered.
+/// Returns a `LowA5Rank` of the best lo hand, or `None` if no qualifying lo hand can be made on the
+/// board (i.e. the board has less than 3 distinct cards ranked 8 or lower).
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::{core::Card, poker::evaluators::omaha_hilo_evaluator};
+///
+/// let board = Card::vec_from_str("2c7dKh4s").unwrap();
+///
+/// let lo_rank = omaha_hilo_evaluator::low_nuts(&board).unwrap();
+///
+/// assert_eq!(lo_rank.description.as_ref().unwrap(), "7-4-3-2-A");
+/// ```
+pub fn low_nuts(board: &[Card]) -> Option<LowA5Rank> {
+    let board_cards = BitDeck::from_cards(board);
+    let remaining_cards: Vec<Card> = CardDeck::default()
+        .filter(|card| !board_cards.contains(card))
+        .collect();
+
+    remaining_cards
+        .iter()
+        .cloned()
+        .combinations(2)
+        .filter_map(|hole_cards| best_lo_hand(&hole_cards, board))
+        .max()
+}
+
+/// The outcome of an Omaha Hi-Lo showdown
+///
+/// The pot is split in half between the hi winners and the lo winners, where each half is split
+/// evenly among the players that tie for it. If no player has a qualifying lo hand, the hi winners
+/// split the whole pot instead.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HiLoShowdown<T: Hash + Eq + Copy> {
+    /// The keys of the players with the best hi hand
+    pub hi_winners: HashSet<T>,
+    /// The keys of the players with the best lo hand, which is empty if no player qualified
+    pub lo_winners: HashSet<T>,
+    /// The fraction of the pot each player receives, including the players that receive nothing
+    pub pot_shares: HashMap<T, f64>,
+}
+
+impl<T: Hash + Eq + Copy> HiLoShowdown<T> {
+    /// Returns the fraction of the pot the player receives
+    ///
+    /// Returns 0 if the key did not participate in the showdown.
+    pub fn pot_share(&self, key: &T) -> f64 {
+        self.pot_shares.get(key).cloned().unwrap_or(0.0)
+    }
+}
+
+/// Evaluates every player's Omaha Hi-Lo hand and allocates the pot between them
+///
+/// Returns a `HiLoShowdown` containing the hi winners, the lo winners, and the fraction of the pot
+/// each player receives (see `HiLoShowdown` for how the pot is split). The keys are expected to be
+/// unique.
+///
+/// Each player's hand is validated identically to `evaluate_hand()`. If duplicate cards are found
+/// across all of the players' hands and the board, a `FailedToCalculateRank` error will return.
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::{core::Card, poker::evaluators::omaha_hilo_evaluator};
+///
+/// let board = Card::vec_from_str("2c3d8hKsKd").unwrap();
+/// let players = [
+///     ("hero", Card::vec_from_str("KhKc9s9c").unwrap()),
+///     ("villain", Card::vec_from_str("Ah4hQcJc").unwrap()),
+/// ];
+///
+/// let showdown = omaha_hilo_evaluator::showdown(&players, &board).unwrap();
+///
+/// assert_eq!(showdown.pot_share(&"hero"), 0.5);
+/// assert_eq!(showdown.pot_share(&"villain"), 0.5);
+/// ```
+pub fn showdown<T: Hash + Eq + Copy>(
+    players: &[(T, Vec<Card>)],
+    board: &[Card],
+) -> Result<HiLoShowdown<T>, EvaluatorError> {
+    let card_sets = || {
+        players
             .iter()
-            .cartesian_product(board_combinations.iter())
-            .map(|(hand_combo, board_combo)| {
-                let cards: HashSet<Card> = hand_combo
-                    .iter()
-                    .chain(board_combo.iter())
-                    .cloned()
-                    .collect();
-                if cards.len() != 5 {
-                    return None;
-                }
-
-                let bit_strength = cards
-                    .iter()
-                    .fold(0, |acc, card| acc | (1 << ((card.value as u8 + 1) % 13)));
-
-                if let Some(&(strength, hand_rank, sub_rank, desc)) = LO_8_MAP.get(&bit_strength) {
-                    Some(LowA5Rank(BasicRank {
-                        strength,
-                        hand_rank,
-                        sub_rank,
-                        description: Some(desc.to_string()),
-                    }))
-                } else {
-                    None
-                }
-            })
-            .fold(None, |acc, rank| if acc < rank { rank } else { acc });
+            .map(|(_, hand)| hand.as_slice())
+            .chain(std::iter::once(board))
+    };
+    let card_count: usize = card_sets().map(|cards| cards.len()).sum();
+    let all_cards_mask = card_sets().fold(0, |acc, cards| acc | card_mask(cards));
+    if card_count != all_cards_mask.count_ones() as usize {
+        return Err(EvaluatorError::FailedToCalculateRank(
+            "Found duplicate cards".to_string(),
+        ));
     }
 
-    Ok(OmahaHiLoRank {
-        hi_rank: hi_hand,
-        lo_rank: lo_hand,
+    let ranks = players
+        .iter()
+        .map(|(key, hand)| Ok((*key, evaluate_hand(hand, board)?)))
+        .collect::<Result<Vec<_>, EvaluatorError>>()?;
+
+    let results = rank_field(&ranks);
+    let winners = |i: usize| {
+        results
+            .get(i)
+            .and_then(|rank_results| rank_results.hand_number(0))
+            .and_then(|ranking_list| ranking_list.first())
+            .cloned()
+            .unwrap_or_default()
+    };
+    let hi_winners = winners(0);
+    let lo_winners = winners(1);
+
+    let hi_pot = if lo_winners.is_empty() { 1.0 } else { 0.5 };
+    let mut pot_shares: HashMap<T, f64> = players.iter().map(|&(key, _)| (key, 0.0)).collect();
+    for key in &hi_winners {
+        *pot_shares.get_mut(key).unwrap() += hi_pot / hi_winners.len() as f64;
+    }
+    for key in &lo_winners {
+        *pot_shares.get_mut(key).unwrap() += (1.0 - hi_pot) / lo_winners.len() as f64;
+    }
+
+    Ok(HiLoShowdown {
+        hi_winners,
+        lo_winners,
+        pot_shares,
     })
 }
 
@@ -159,3 +298,112 @@ static LO_8_MAP: phf::Map<u8, (u32, u16, u16, &'static str)> = phf_map! {
     0x2fu8 => (55, 3, 5, "6-4-3-2-A"),
     0x1fu8 => (56, 4, 1, "5-4-3-2-A"),
 };
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Suit;
+    use crate::poker::evaluators::low_a5_evaluator;
+    use crate::poker::ranks::LowRank;
+    use num_traits::FromPrimitive;
+
+    #[test]
+    fn showdown_scoop_hi_and_chop_lo() {
+        let board = Card::vec_from_str("2c3d8hKsKd").unwrap();
+        let players = [
+            (1, Card::vec_from_str("KhKc9s9c").unwrap()),
+            (2, Card::vec_from_str("Ah4hQcJc").unwrap()),
+            (3, Card::vec_from_str("As4sQdJd").unwrap()),
+        ];
+
+        let showdown = showdown(&players, &board).expect("Showdown failed");
+
+        assert_eq!(showdown.hi_winners, HashSet::from([1]));
+        assert_eq!(showdown.lo_winners, HashSet::from([2, 3]));
+        assert_eq!(showdown.pot_share(&1), 0.5);
+        assert_eq!(showdown.pot_share(&2), 0.25);
+        assert_eq!(showdown.pot_share(&3), 0.25);
+        assert_eq!(showdown.pot_share(&4), 0.0);
+    }
+
+    #[test]
+    fn showdown_without_lo() {
+        let board = Card::vec_from_str("2c9dJhKsKd").unwrap();
+        let players = [
+            (1, Card::vec_from_str("KhKc9s9c").unwrap()),
+            (2, Card::vec_from_str("Ah4hQcJc").unwrap()),
+        ];
+
+        let showdown = showdown(&players, &board).expect("Showdown failed");
+
+        assert!(showdown.lo_winners.is_empty());
+        assert_eq!(showdown.pot_share(&1), 1.0);
+        assert_eq!(showdown.pot_share(&2), 0.0);
+    }
+
+    #[test]
+    fn showdown_duplicate_cards() {
+        let board = Card::vec_from_str("2c3d8hKsKd").unwrap();
+        let players = [
+            (1, Card::vec_from_str("KhKc9s9c").unwrap()),
+            (2, Card::vec_from_str("Ah4hQcKh").unwrap()),
+        ];
+
+        assert_eq!(
+            showdown(&players, &board).expect_err("Evaluated duplicate cards"),
+            EvaluatorError::FailedToCalculateRank("Found duplicate cards".to_string())
+        );
+    }
+
+    #[test]
+    fn low_nuts_wheel() {
+        let board = Card::vec_from_str("3c4d5hKsQs").unwrap();
+
+        let lo_rank = low_nuts(&board).expect("No low found");
+
+        assert_eq!(lo_rank.description.as_ref().unwrap(), "5-4-3-2-A");
+        assert_eq!(lo_rank.strength, 56);
+    }
+
+    #[test]
+    fn lo_strength_matches_razz_rank() {
+        let suits = "hcds";
+        for (&bits, &(_, _, _, description)) in LO_8_MAP.entries() {
+            let hand: Vec<Card> = (0..8)
+                .filter(|rank| bits & (1 << rank) != 0)
+                .zip(suits.chars().cycle())
+                .map(|(rank, suit)| Card {
+                    value: Value::from_u8((rank + 12) % 13).unwrap(),
+                    suit: Suit::from_char(suit).unwrap(),
+                })
+                .collect();
+            let lo_rank = best_lo_hand(&hand[..2], &hand[2..]).expect("Hand should have a lo");
+
+            let razz_rank = low_a5_evaluator::evaluate_hand(&hand).expect("Evaluation failed");
+
+            assert_eq!(lo_rank.lo_strength(), razz_rank.lo_strength());
+            assert_eq!(lo_rank.description.as_deref(), Some(description));
+            assert_eq!(razz_rank.description.as_deref(), Some(description));
+        }
+    }
+
+    #[test]
+    fn low_nuts_uses_board_cards() {
+        let board = Card::vec_from_str("8c6d2hKs").unwrap();
+
+        let lo_rank = low_nuts(&board).expect("No low found");
+
+        // the best 2 cards from a player (A-3) must fill in around the 8-6-2 on the board
+        assert_eq!(lo_rank.description.as_ref().unwrap(), "8-6-3-2-A");
+    }
+
+    #[test]
+    fn low_nuts_no_low_possible() {
+        // only two distinct low ranks on the board
+        let board = Card::vec_from_str("2c2d7hKsQs").unwrap();
+        assert_eq!(low_nuts(&board), None);
+
+        let board = Card::vec_from_str("9cTdJh").unwrap();
+        assert_eq!(low_nuts(&board), None);
+    }
+}
@@ -0,0 +1,186 @@
+use super::{high_evaluator, EvaluatorError};
+
+use itertools::Itertools;
+
+use crate::core::{Card, Value};
+use crate::poker::ranks::{BasicRank, HighRank, ShortDeckRank};
+
+// (hand rank, distinct combos) from weakest to strongest in short deck
+const SHORT_DECK_CATEGORY_ORDER: [(u16, u32); 9] = [
+    (1, 1277), // high card
+    (2, 2860), // pair
+    (3, 858),  // two pair
+    (5, 10),   // straight
+    (4, 858),  // trips
+    (7, 156),  // full house
+    (6, 1277), // flush
+    (8, 156),  // quads
+    (9, 10),   // straight flush
+];
+
+/// Evaluates the short deck (6+ Hold'em) high hand for one player
+///
+/// Returns a `ShortDeckRank`. If the total card count is not with the domain [5, 7], then either a
+/// `NotEnoughCards` or a `TooManyCards` will return respective to whether not enough or too many
+/// cards were given.
+///
+/// Short deck is played with a 36-card deck where all 2s through 5s are removed. Since there are
+/// less cards of each suit, a flush beats a full house and trips beat a straight. The `hand_rank`,
+/// `sub_rank`, and `description` of the rank are identical to those generated by the high
+/// evaluator, but the `strength` is ordered by the short deck hand rankings. Hence, a
+/// `ShortDeckRank` should not be compared against a `HighRank`.
+///
+/// An Ace can also play low to make A-6-7-8-9, the lowest straight in short deck. This hand is
+/// ranked and described identically to a 9 high straight (or straight flush) of the high
+/// evaluator, placing it below 6-7-8-9-10.
+///
+/// This implementation does not support the use of duplicate cards or cards ranked lower than a
+/// 6. If either are found, a `FailedToCalculateRank` error will return.
+pub fn evaluate_hand(cards: &[Card]) -> Result<ShortDeckRank, EvaluatorError> {
+    if cards.iter().any(|card| card.value < Value::Six) {
+        return Err(EvaluatorError::FailedToCalculateRank(
+            "Found cards not within a short deck".to_string(),
+        ));
+    }
+
+    // validates the card count and checks for duplicates
+    high_evaluator::evaluate_hand(cards)?;
+
+    cards
+        .iter()
+        .cloned()
+        .combinations(5)
+        .map(|hand| high_evaluator::evaluate_hand(&play_ace_low(hand)).map(to_short_deck_rank))
+        .reduce(|acc, rank_res| {
+            let acc = acc?;
+            let rank = rank_res?;
+            Ok(std::cmp::max(rank, acc))
+        })
+        .unwrap_or(Err(EvaluatorError::FailedToCalculateRank(
+            "No hand combos were evaluated".to_string(),
+        )))
+}
+
+/// Replaces the Ace of an A-6-7-8-9 hand with a 5 of the same suit
+///
+/// Since short deck has no 5s, the high evaluator will then rank the hand as the 9 high straight
+/// it plays as. Any other hand is returned as is.
+fn play_ace_low(mut hand: Vec<Card>) -> Vec<Card> {
+    const WHEEL_VALUES: [Value; 5] = [
+        Value::Ace,
+        Value::Six,
+        Value::Seven,
+        Value::Eight,
+        Value::Nine,
+    ];
+    if WHEEL_VALUES
+        .iter()
+        .all(|value| hand.iter().any(|card| card.value == *value))
+    {
+        for card in hand.iter_mut().filter(|card| card.value == Value::Ace) {
+            card.value = Value::Five;
+        }
+    }
+    hand
+}
+
+fn to_short_deck_rank(high_rank: HighRank) -> ShortDeckRank {
+    let weaker_hands: u32 = SHORT_DECK_CATEGORY_ORDER
+        .iter()
+        .take_while(|&&(hand_rank, _)| hand_rank != high_rank.hand_rank)
+        .map(|&(_, count)| count)
+        .sum();
+
+    let mut rank: BasicRank = high_rank.0;
+    rank.strength = weaker_hands + rank.sub_rank as u32;
+    ShortDeckRank(rank)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flush_beats_full_house() {
+        let flush = Card::vec_from_str("6h8h9hJhKh").unwrap();
+        let full_house = Card::vec_from_str("AsAdAcKsKd").unwrap();
+
+        let flush_rank = evaluate_hand(&flush).expect("Evaluation failed");
+        let full_house_rank = evaluate_hand(&full_house).expect("Evaluation failed");
+
+        assert_eq!(flush_rank.description.as_ref().unwrap(), "King High Flush");
+        assert_eq!(
+            full_house_rank.description.as_ref().unwrap(),
+            "Aces Full of Kings"
+        );
+        assert!(flush_rank > full_house_rank);
+    }
+
+    #[test]
+    fn trips_beat_straight() {
+        let trips = Card::vec_from_str("6s6d6cKsQd").unwrap();
+        let straight = Card::vec_from_str("TsJdQcKhAs").unwrap();
+
+        let trips_rank = evaluate_hand(&trips).expect("Evaluation failed");
+        let straight_rank = evaluate_hand(&straight).expect("Evaluation failed");
+
+        assert!(trips_rank > straight_rank);
+    }
+
+    #[test]
+    fn ace_low_straight() {
+        let wheel = Card::vec_from_str("As6d7c8h9s").unwrap();
+        let ten_high_straight = Card::vec_from_str("6d7c8h9sTd").unwrap();
+        let trips = Card::vec_from_str("6s6d6cKsQd").unwrap();
+        let ace_high = Card::vec_from_str("As6d7c8hTs").unwrap();
+
+        let wheel_rank = evaluate_hand(&wheel).expect("Evaluation failed");
+        let ten_high_straight_rank = evaluate_hand(&ten_high_straight).expect("Evaluation failed");
+        let trips_rank = evaluate_hand(&trips).expect("Evaluation failed");
+        let ace_high_rank = evaluate_hand(&ace_high).expect("Evaluation failed");
+
+        assert_eq!(wheel_rank.hand_rank, 5);
+        assert_eq!(wheel_rank.description.as_ref().unwrap(), "9 High Straight");
+        assert!(wheel_rank < ten_high_straight_rank);
+        assert_eq!(wheel_rank.strength + 1, ten_high_straight_rank.strength);
+        assert!(wheel_rank < trips_rank);
+        assert!(wheel_rank > ace_high_rank);
+    }
+
+    #[test]
+    fn ace_low_straight_flush() {
+        let wheel = Card::vec_from_str("AhKs6h7h8h9h").unwrap();
+        let ten_high_straight_flush = Card::vec_from_str("6h7h8h9hTh").unwrap();
+        let quads = Card::vec_from_str("AsAdAcAhKd").unwrap();
+
+        let wheel_rank = evaluate_hand(&wheel).expect("Evaluation failed");
+
+        assert_eq!(
+            wheel_rank.description.as_ref().unwrap(),
+            "9 High Straight Flush"
+        );
+        assert!(wheel_rank < evaluate_hand(&ten_high_straight_flush).unwrap());
+        assert!(wheel_rank > evaluate_hand(&quads).unwrap());
+    }
+
+    #[test]
+    fn best_hand_from_seven_cards() {
+        let cards = Card::vec_from_str("AhKhQsQdQh7h9h").unwrap();
+
+        let rank = evaluate_hand(&cards).expect("Evaluation failed");
+
+        assert_eq!(rank.description.as_ref().unwrap(), "Ace High Flush");
+    }
+
+    #[test]
+    fn cards_outside_short_deck() {
+        let cards = Card::vec_from_str("AhKhQsQd5h").unwrap();
+
+        assert_eq!(
+            evaluate_hand(&cards).expect_err("Evaluated a card outside of a short deck"),
+            EvaluatorError::FailedToCalculateRank(
+                "Found cards not within a short deck".to_string()
+            )
+        );
+    }
+}
@@ -0,0 +1,405 @@
+use strum_macros::EnumIter;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{card_mask, Card, Value};
+use crate::poker::evaluators::{
+    badugi_evaluator, high_evaluator, low_27_evaluator, low_a5_evaluator, omaha_hi_evaluator,
+    omaha_hilo_evaluator, short_deck_evaluator, EvaluatorError,
+};
+use crate::poker::ranks::GameRank;
+
+/// An enum representation of the supported poker games
+///
+/// Each game knows how many cards a player is dealt and which evaluator should be used to rank a
+/// player's hand, making it easy to build clients that support multiple games.
+///
+/// With the `serde` feature enabled, this enum also implements serde's `Serialize` and
+/// `Deserialize` traits.
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::{core::Card, poker::{GameKind, ranks::GameRank}};
+///
+/// let hand = Card::vec_from_str("AsKs").unwrap();
+/// let board = Card::vec_from_str("QsJsTs2d3c").unwrap();
+///
+/// let rank = GameKind::Holdem.evaluate(&hand, &board).unwrap();
+///
+/// match rank {
+///     GameRank::High(rank) => assert_eq!(rank.description.as_ref().unwrap(), "Ace High Straight Flush"),
+///     _ => unreachable!("Hold'em hands are ranked as high hands"),
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, EnumIter, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GameKind {
+    /// Texas Hold'em
+    Holdem,
+    /// Omaha High
+    OmahaHi,
+    /// Omaha Hi-Lo (8 or better)
+    OmahaHiLo,
+    /// 2-7 Lowball Draw
+    TwoSevenLowball,
+    /// Badugi
+    Badugi,
+    /// Razz (Seven Card Stud with Ace-to-5 low hands)
+    Razz,
+    /// Short deck (6+) Hold'em
+    ShortDeck,
+}
+
+impl GameKind {
+    /// Returns the number of cards a player holds at showdown
+    pub fn required_hole_cards(&self) -> usize {
+        match self {
+            Self::Holdem | Self::ShortDeck => 2,
+            Self::OmahaHi | Self::OmahaHiLo | Self::Badugi => 4,
+            Self::TwoSevenLowball => 5,
+            Self::Razz => 7,
+        }
+    }
+
+    /// Validates that a player's hand is legal for the game
+    ///
+    /// Razz hands must contain between 5 and 7 cards to allow for hands before 7th street, and all
+    /// other games must contain exactly `required_hole_cards()`. If this is not met, then either a
+    /// `NotEnoughCards` or a `TooManyCards` will return. If duplicate cards are found, or short deck
+    /// hands contain cards ranked lower than a 6, then a `FailedToCalculateRank` error will return.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use playing_cards::{core::Card, poker::GameKind};
+    ///
+    /// assert!(GameKind::Badugi.validate(&Card::vec_from_str("As4d7cTh").unwrap()).is_ok());
+    /// assert!(GameKind::Badugi.validate(&Card::vec_from_str("As4d7c").unwrap()).is_err());
+    /// ```
+    pub fn validate(&self, cards: &[Card]) -> Result<(), EvaluatorError> {
+        let (min_cards, max_cards) = match self {
+            Self::Razz => (5, self.required_hole_cards()),
+            _ => (self.required_hole_cards(), self.required_hole_cards()),
+        };
+        check_card_count("Player hand", cards.len(), min_cards, max_cards)?;
+
+        if cards.len() != card_mask(cards).count_ones() as usize {
+            return Err(EvaluatorError::FailedToCalculateRank(
+                "Found duplicate cards".to_string(),
+            ));
+        }
+
+        if *self == Self::ShortDeck && cards.iter().any(|card| card.value < Value::Six) {
+            return Err(EvaluatorError::FailedToCalculateRank(
+                "Found cards not within a short deck".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the minimum and maximum number of board cards for the game
+    pub(crate) fn board_card_bounds(&self) -> (usize, usize) {
+        match self {
+            Self::Holdem | Self::ShortDeck | Self::OmahaHi | Self::OmahaHiLo => (3, 5),
+            Self::TwoSevenLowball | Self::Badugi | Self::Razz => (0, 0),
+        }
+    }
+
+    /// Evaluates a player's hand with the evaluator for the game
+    ///
+    /// Returns a `GameRank` wrapping the rank produced by the game's evaluator. Games that have no
+    /// community cards require an empty board.
+    ///
+    /// Flop games (Hold'em, Omaha, and short deck) require exactly `required_hole_cards()` in the
+    /// player's hand and between 3 and 5 cards on the board. Draw games (2-7 and Badugi) require
+    /// exactly `required_hole_cards()`. Razz requires between 5 and 7 cards to allow for
+    /// evaluating hands before 7th street. If any of these are not met, then either a
+    /// `NotEnoughCards` or a `TooManyCards` will return. The player's hand is checked with
+    /// `validate()` before evaluating. Any other errors from the evaluator are forwarded as is.
+    pub fn evaluate(&self, hole: &[Card], board: &[Card]) -> Result<GameRank, EvaluatorError> {
+        self.validate(hole)?;
+
+        let (min_board_cards, max_board_cards) = self.board_card_bounds();
+        check_card_count("Board", board.len(), min_board_cards, max_board_cards)?;
+
+        let rank = match self {
+            Self::Holdem => GameRank::High(high_evaluator::evaluate_iter(
+                hole.iter().chain(board.iter()).cloned(),
+            )?),
+            Self::ShortDeck => GameRank::ShortDeck(short_deck_evaluator::evaluate_hand(
+                &[hole, board].concat(),
+            )?),
+            Self::OmahaHi => GameRank::High(omaha_hi_evaluator::evaluate_hand(hole, board)?),
+            Self::OmahaHiLo => GameRank::HiLo(omaha_hilo_evaluator::evaluate_hand(hole, board)?),
+            Self::TwoSevenLowball => GameRank::Low27(low_27_evaluator::evaluate_hand(hole)?),
+            Self::Badugi => GameRank::Badugi(badugi_evaluator::evaluate_hand(hole)?),
+            Self::Razz => GameRank::Razz(low_a5_evaluator::evaluate_hand(hole)?),
+        };
+
+        Ok(rank)
+    }
+}
+
+/// Checks that `count` is within the domain [`min`, `max`], returning a `NotEnoughCards` or a
+/// `TooManyCards` for `card_set_type` otherwise
+pub(crate) fn check_card_count(
+    card_set_type: &str,
+    count: usize,
+    min: usize,
+    max: usize,
+) -> Result<(), EvaluatorError> {
+    if count < min {
+        return Err(EvaluatorError::NotEnoughCards {
+            card_set_type: card_set_type.to_string(),
+            expected_count: min as u64,
+            actual_count: count as u64,
+        });
+    } else if count > max {
+        return Err(EvaluatorError::TooManyCards {
+            card_set_type: card_set_type.to_string(),
+            expected_count: max as u64,
+            actual_count: count as u64,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    fn cards(s: &str) -> Vec<Card> {
+        Card::vec_from_str(s).unwrap()
+    }
+
+    fn description(rank: &GameRank) -> &str {
+        match rank {
+            GameRank::High(rank) => rank.description.as_ref().unwrap(),
+            GameRank::ShortDeck(rank) => rank.description.as_ref().unwrap(),
+            GameRank::HiLo(rank) => rank.hi_rank.description.as_ref().unwrap(),
+            GameRank::Low27(rank) => rank.description.as_ref().unwrap(),
+            GameRank::Razz(rank) => rank.description.as_ref().unwrap(),
+            GameRank::Badugi(rank) => rank.description.as_ref().unwrap(),
+        }
+    }
+
+    #[test]
+    fn holdem() {
+        let rank = GameKind::Holdem
+            .evaluate(&cards("5h5s"), &cards("2dTdKs5dAc"))
+            .expect("Evaluation failed");
+        assert_eq!(description(&rank), "Trip 5s");
+
+        assert_eq!(
+            GameKind::Holdem
+                .evaluate(&cards("5h5s2c"), &cards("2dTdKs5dAc"))
+                .expect_err("Evaluated 3 hole cards"),
+            EvaluatorError::TooManyCards {
+                card_set_type: "Player hand".to_string(),
+                expected_count: 2,
+                actual_count: 3,
+            }
+        );
+        assert_eq!(
+            GameKind::Holdem
+                .evaluate(&cards("5h5s"), &cards("2dTd"))
+                .expect_err("Evaluated a 2 card board"),
+            EvaluatorError::NotEnoughCards {
+                card_set_type: "Board".to_string(),
+                expected_count: 3,
+                actual_count: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn omaha_hi() {
+        let rank = GameKind::OmahaHi
+            .evaluate(&cards("2cAsAcKc"), &cards("Ks2sTd8h7d"))
+            .expect("Evaluation failed");
+        assert_eq!(description(&rank), "Two Pair of Kings and 2s");
+
+        assert_eq!(
+            GameKind::OmahaHi
+                .evaluate(&cards("2cAsAcKc3d"), &cards("Ks2sTd8h7d"))
+                .expect_err("Evaluated 5 hole cards"),
+            EvaluatorError::TooManyCards {
+                card_set_type: "Player hand".to_string(),
+                expected_count: 4,
+                actual_count: 5,
+            }
+        );
+    }
+
+    #[test]
+    fn omaha_hilo() {
+        let rank = GameKind::OmahaHiLo
+            .evaluate(&cards("As2d5sAd"), &cards("Tc5c3s6c8c"))
+            .expect("Evaluation failed");
+        match rank {
+            GameRank::HiLo(rank) => {
+                assert_eq!(rank.hi_rank.description.as_ref().unwrap(), "Pair of Aces");
+                assert_eq!(
+                    rank.lo_rank.unwrap().description.as_ref().unwrap(),
+                    "6-5-3-2-A"
+                );
+            }
+            _ => panic!("Omaha Hi-Lo did not return a hi-lo rank"),
+        }
+
+        assert_eq!(
+            GameKind::OmahaHiLo
+                .evaluate(&cards("As2d5s"), &cards("Tc5c3s6c8c"))
+                .expect_err("Evaluated 3 hole cards"),
+            EvaluatorError::NotEnoughCards {
+                card_set_type: "Player hand".to_string(),
+                expected_count: 4,
+                actual_count: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn two_seven_lowball() {
+        let rank = GameKind::TwoSevenLowball
+            .evaluate(&cards("6h7h2s3cTd"), &[])
+            .expect("Evaluation failed");
+        assert!(matches!(rank, GameRank::Low27(_)));
+        assert_eq!(description(&rank), "T-7-6-3-2");
+
+        assert_eq!(
+            GameKind::TwoSevenLowball
+                .evaluate(&cards("6h7h2s3cTd"), &cards("Ah"))
+                .expect_err("Evaluated a draw game with a board"),
+            EvaluatorError::TooManyCards {
+                card_set_type: "Board".to_string(),
+                expected_count: 0,
+                actual_count: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn badugi() {
+        let rank = GameKind::Badugi
+            .evaluate(&cards("As4d7cTh"), &[])
+            .expect("Evaluation failed");
+        assert_eq!(description(&rank), "10-high Badugi");
+
+        assert_eq!(
+            GameKind::Badugi
+                .evaluate(&cards("As4d7cTh2h"), &[])
+                .expect_err("Evaluated a 5 card Badugi hand"),
+            EvaluatorError::TooManyCards {
+                card_set_type: "Player hand".to_string(),
+                expected_count: 4,
+                actual_count: 5,
+            }
+        );
+    }
+
+    #[test]
+    fn razz() {
+        let rank = GameKind::Razz
+            .evaluate(&cards("7c5d3h2s8dKsKd"), &[])
+            .expect("Evaluation failed");
+        assert_eq!(description(&rank), "8-7-5-3-2");
+
+        let rank = GameKind::Razz
+            .evaluate(&cards("7c5d3h2s8d"), &[])
+            .expect("Evaluation failed");
+        assert_eq!(description(&rank), "8-7-5-3-2");
+
+        assert_eq!(
+            GameKind::Razz
+                .evaluate(&cards("7c5d3h2s"), &[])
+                .expect_err("Evaluated a 4 card Razz hand"),
+            EvaluatorError::NotEnoughCards {
+                card_set_type: "Player hand".to_string(),
+                expected_count: 5,
+                actual_count: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn short_deck() {
+        let rank = GameKind::ShortDeck
+            .evaluate(&cards("AhTh"), &cards("Kh7h9hJc6s"))
+            .expect("Evaluation failed");
+        assert!(matches!(rank, GameRank::ShortDeck(_)));
+        assert_eq!(description(&rank), "Ace High Flush");
+
+        assert_eq!(
+            GameKind::ShortDeck
+                .evaluate(&cards("Ah"), &cards("Kh7h9hJc6s"))
+                .expect_err("Evaluated 1 hole card"),
+            EvaluatorError::NotEnoughCards {
+                card_set_type: "Player hand".to_string(),
+                expected_count: 2,
+                actual_count: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn validate_boundaries() {
+        let deck = cards("AsKsQsJsTs9s8s7s");
+        for game in GameKind::iter() {
+            let max = game.required_hole_cards();
+            let min = if game == GameKind::Razz { 5 } else { max };
+
+            assert_eq!(
+                game.validate(&deck[..min - 1]),
+                Err(EvaluatorError::NotEnoughCards {
+                    card_set_type: "Player hand".to_string(),
+                    expected_count: min as u64,
+                    actual_count: min as u64 - 1,
+                }),
+                "{:?} accepted {} cards",
+                game,
+                min - 1
+            );
+            for count in min..=max {
+                assert_eq!(
+                    game.validate(&deck[..count]),
+                    Ok(()),
+                    "{:?} rejected {} cards",
+                    game,
+                    count
+                );
+            }
+            assert_eq!(
+                game.validate(&deck[..max + 1]),
+                Err(EvaluatorError::TooManyCards {
+                    card_set_type: "Player hand".to_string(),
+                    expected_count: max as u64,
+                    actual_count: max as u64 + 1,
+                }),
+                "{:?} accepted {} cards",
+                game,
+                max + 1
+            );
+        }
+    }
+
+    #[test]
+    fn validate_dead_hands() {
+        assert_eq!(
+            GameKind::Badugi.validate(&cards("As4d7cAs")),
+            Err(EvaluatorError::FailedToCalculateRank(
+                "Found duplicate cards".to_string()
+            ))
+        );
+        assert_eq!(
+            GameKind::ShortDeck.validate(&cards("As5s")),
+            Err(EvaluatorError::FailedToCalculateRank(
+                "Found cards not within a short deck".to_string()
+            ))
+        );
+        assert_eq!(GameKind::Holdem.validate(&cards("As5s")), Ok(()));
+    }
+}
@@ -3,5 +3,29 @@ pub(crate) mod tables;
 
 pub mod evaluators;
 
+/// Contains helpers for checking which cards a player blocks
+pub mod blockers;
+
+/// Contains Monte Carlo equity calculations for Hold'em hands
+pub mod equity;
+
+/// Contains a memoizing high hand evaluator for repeated evaluations of the same cards
+pub mod eval_cache;
+
+mod game_kind;
+pub use game_kind::*;
+
 /// Contains structs for contains rank metadata and logic for parsing ranks
 pub mod ranks;
+
+/// Contains helpers for counting and grouping the values and suits within a hand
+pub mod stats;
+
+/// Contains helpers specific to Stud games
+pub mod stud;
+
+/// Contains helpers for studying hand matchups
+pub mod study;
+
+/// Contains helpers for generating random evaluator inputs for testing
+pub mod testutil;
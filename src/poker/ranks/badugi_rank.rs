@@ -1,9 +1,16 @@
 use std::ops::Deref;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use super::{BasicRank, IntoRankStrengthIterator, RankStrengthIterator};
 
 /// A rank of a Badugi hand
+///
+/// With the `serde` feature enabled, this struct also implements serde's `Serialize` and
+/// `Deserialize` traits.
 #[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BadugiRank(pub BasicRank);
 
 impl Deref for BadugiRank {
@@ -1,5 +1,8 @@
 use std::cmp::Ordering;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// A foundational struct to contain hand strength metadata
 ///
 /// The `BasicRank` struct is fairly transparent allowing for easy access for evaluators to create
@@ -14,7 +17,11 @@ use std::cmp::Ordering;
 /// Ranks can be compared with each other using the typical equality and inequality operations.
 /// The evaluators that are provided in the `evaluator` module produce structs that rely on this
 /// foundational struct.
+///
+/// With the `serde` feature enabled, this struct also implements serde's `Serialize` and
+/// `Deserialize` traits.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BasicRank {
     /// The strength of the `Rank`
     ///
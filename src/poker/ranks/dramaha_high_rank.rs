@@ -1,7 +1,14 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use super::{HighRank, IntoRankStrengthIterator, RankStrengthIterator};
 
 /// A struct of ranks a Dramaha High hand
+///
+/// With the `serde` feature enabled, this struct also implements serde's `Serialize` and
+/// `Deserialize` traits.
 #[derive(Debug, Clone, Eq, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DramahaHighRank {
     /// The Omaha high rank from the hand
     pub omaha_rank: HighRank,
@@ -0,0 +1,39 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{
+    BadugiRank, HighRank, IntoRankStrengthIterator, Low27Rank, OmahaHiLoRank, RankStrengthIterator,
+    RazzRank, ShortDeckRank,
+};
+
+/// A unified rank returned when evaluating a hand through a `GameKind`
+///
+/// Each variant wraps the rank type produced by the evaluator of the game. Ranks should only be
+/// compared against other ranks from the same `GameKind`.
+///
+/// With the `serde` feature enabled, this enum also implements serde's `Serialize` and
+/// `Deserialize` traits.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GameRank {
+    High(HighRank),
+    ShortDeck(ShortDeckRank),
+    HiLo(OmahaHiLoRank),
+    Low27(Low27Rank),
+    Razz(RazzRank),
+    Badugi(BadugiRank),
+}
+
+impl IntoRankStrengthIterator for GameRank {
+    fn into_strength_iter(self) -> RankStrengthIterator {
+        match self {
+            Self::High(rank) => rank.into_strength_iter(),
+            Self::ShortDeck(rank) => rank.into_strength_iter(),
+            Self::HiLo(rank) => rank.into_strength_iter(),
+            Self::Low27(rank) => rank.into_strength_iter(),
+            Self::Razz(rank) => rank.into_strength_iter(),
+            Self::Badugi(rank) => rank.into_strength_iter(),
+        }
+    }
+}
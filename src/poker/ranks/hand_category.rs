@@ -0,0 +1,24 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An enum representation of the category of a high hand
+///
+/// Each numeric value corresponds to the `hand_rank` of a `HighRank` generated by the high
+/// evaluator, with a higher value being a stronger category.
+///
+/// With the `serde` feature enabled, this enum also implements serde's `Serialize` and
+/// `Deserialize` traits.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, FromPrimitive, ToPrimitive, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HandCategory {
+    HighCard = 1,
+    Pair = 2,
+    TwoPair = 3,
+    Trips = 4,
+    Straight = 5,
+    Flush = 6,
+    FullHouse = 7,
+    Quads = 8,
+    StraightFlush = 9,
+}
@@ -1,11 +1,107 @@
 use std::ops::Deref;
 
-use super::{BasicRank, IntoRankStrengthIterator, RankStrengthIterator};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{BasicRank, HandCategory, IntoRankStrengthIterator, RankStrengthIterator};
+use crate::poker::evaluators::high_evaluator;
+
+const DISTINCT_HIGH_RANKS: u32 = 7462;
 
 /// A rank of a high hand
+///
+/// With the `serde` feature enabled, this struct also implements serde's `Serialize` and
+/// `Deserialize` traits.
 #[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct HighRank(pub BasicRank);
 
+impl HighRank {
+    /// Returns true if the hand is of the provided category or a stronger category
+    ///
+    /// This is useful for thresholding hands (e.g. "is this hand at least a flush?"). Categories
+    /// are compared by the standard high hand ordering of `HandCategory`.
+    pub fn at_least(&self, category: HandCategory) -> bool {
+        self.hand_rank >= category as u16
+    }
+
+    /// Returns true if the hand is a 5-high straight (A-2-3-4-5), also known as a wheel
+    ///
+    /// A 5-high straight flush (a steel wheel) is also considered a wheel. Since the wheel is the
+    /// weakest straight, it is always the first sub rank of its category.
+    pub fn is_wheel_straight(&self) -> bool {
+        (self.hand_rank == HandCategory::Straight as u16
+            || self.hand_rank == HandCategory::StraightFlush as u16)
+            && self.sub_rank == 1
+    }
+
+    /// Returns the number of distinct 5-card high hand ranks that beat this rank
+    ///
+    /// There are 7462 distinct high hand ranks, so a royal flush has 0 stronger hands and the
+    /// worst high card (7-5-4-3-2) has 7461 stronger hands. This is only meaningful for ranks
+    /// generated by `high_evaluator::evaluate_hand()` or evaluators built on top of it.
+    ///
+    /// Returns None if the strength is not within the domain [1, 7462]. This is the case for
+    /// ranks from `high_evaluator::evaluate_partial()`, which use a different scale.
+    pub fn hands_stronger(&self) -> Option<u32> {
+        if !(1..=DISTINCT_HIGH_RANKS).contains(&self.strength) {
+            return None;
+        }
+
+        Some(DISTINCT_HIGH_RANKS - self.strength)
+    }
+
+    /// Returns the percentile of the rank among all distinct 5-card high hand ranks
+    ///
+    /// This is the percentage of distinct ranks that are weaker than this rank, being derived
+    /// from `hands_stronger()`. A royal flush is at the 100th percentile and the worst high card
+    /// is at the 0th percentile. Returns None if `hands_stronger()` returns None.
+    pub fn percentile(&self) -> Option<f64> {
+        let hands_weaker = DISTINCT_HIGH_RANKS - 1 - self.hands_stronger()?;
+        Some(hands_weaker as f64 / (DISTINCT_HIGH_RANKS - 1) as f64 * 100.0)
+    }
+
+    /// Encodes the rank into bytes where a bytewise comparison matches the rank ordering
+    ///
+    /// The bytes are the big-endian `strength` of the rank, so a stronger rank always has greater
+    /// bytes. This is useful for storing ranks in sorted indices (e.g. database range scans by
+    /// hand strength). Only the strength is encoded, and the rank can be recovered with
+    /// `from_sortable_bytes()`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use playing_cards::{core::Card, poker::{evaluators::high_evaluator, ranks::HighRank}};
+    ///
+    /// let rank = high_evaluator::evaluate_hand(&Card::vec_from_str("KhKdKc2s2h").unwrap()).unwrap();
+    ///
+    /// let bytes = rank.to_sortable_bytes();
+    ///
+    /// assert_eq!(HighRank::from_sortable_bytes(bytes), Some(rank));
+    /// ```
+    pub fn to_sortable_bytes(&self) -> [u8; 4] {
+        self.strength.to_be_bytes()
+    }
+
+    /// Decodes a rank from the bytes generated by `to_sortable_bytes()`
+    ///
+    /// The `hand_rank`, `sub_rank`, and `description` are regenerated from the strength, so the
+    /// bytes must come from a rank generated by `high_evaluator::evaluate_hand()` or evaluators
+    /// built on top of it, in which case the returned rank is identical to the encoded one.
+    /// Returns None if the bytes do not contain a valid high hand strength (i.e. the strength is
+    /// not within the domain [1, 7462]), such as those of a rank from
+    /// `high_evaluator::evaluate_partial()`.
+    pub fn from_sortable_bytes(bytes: [u8; 4]) -> Option<HighRank> {
+        let strength = u32::from_be_bytes(bytes);
+        if !(1..=DISTINCT_HIGH_RANKS).contains(&strength) {
+            return None;
+        }
+
+        Some(high_evaluator::to_high_rank(
+            (DISTINCT_HIGH_RANKS + 1 - strength) as u16,
+        ))
+    }
+}
+
 impl Deref for HighRank {
     type Target = BasicRank;
     fn deref(&self) -> &Self::Target {
@@ -18,3 +114,129 @@ impl IntoRankStrengthIterator for HighRank {
         RankStrengthIterator::from(self.strength)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{core::Card, poker::evaluators::high_evaluator};
+
+    #[test]
+    fn sortable_bytes_match_rank_ordering() {
+        let hands = [
+            "2c3d4h5s7c", // 7 high
+            "AcKdQhJs9c", // ace high
+            "2c2d4h5s7c", // pair of 2s
+            "KcKdKhKs2c", // quad kings
+            "AsKsQsJsTs", // royal flush
+        ];
+        let ranks: Vec<HighRank> = hands
+            .iter()
+            .map(|h| high_evaluator::evaluate_hand(&Card::vec_from_str(h).unwrap()).unwrap())
+            .collect();
+
+        for a in &ranks {
+            for b in &ranks {
+                assert_eq!(
+                    a.cmp(b),
+                    a.to_sortable_bytes().cmp(&b.to_sortable_bytes()),
+                    "Byte ordering differs for {:?} and {:?}",
+                    a.description,
+                    b.description
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn sortable_bytes_round_trip() {
+        for strength in 1..=DISTINCT_HIGH_RANKS {
+            let rank = HighRank::from_sortable_bytes(strength.to_be_bytes()).unwrap();
+
+            assert_eq!(rank.strength, strength);
+            assert_eq!(rank.to_sortable_bytes(), strength.to_be_bytes());
+        }
+
+        let full_house =
+            high_evaluator::evaluate_hand(&Card::vec_from_str("KhKdKc2s2h").unwrap()).unwrap();
+        assert_eq!(
+            HighRank::from_sortable_bytes(full_house.to_sortable_bytes()),
+            Some(full_house)
+        );
+
+        assert_eq!(HighRank::from_sortable_bytes([0; 4]), None);
+        assert_eq!(HighRank::from_sortable_bytes(7463u32.to_be_bytes()), None);
+    }
+
+    #[test]
+    fn sortable_bytes_reject_partial_rank() {
+        let aces = high_evaluator::evaluate_partial(&Card::vec_from_str("AsAd").unwrap())
+            .expect("Evaluation failed");
+
+        assert_eq!(HighRank::from_sortable_bytes(aces.to_sortable_bytes()), None);
+    }
+
+    #[test]
+    fn flush_at_least() {
+        let rank = high_evaluator::evaluate_hand(&Card::vec_from_str("2s3s4s5s7s").unwrap())
+            .expect("Evaluation failed");
+
+        assert!(rank.at_least(HandCategory::HighCard));
+        assert!(rank.at_least(HandCategory::Straight));
+        assert!(rank.at_least(HandCategory::Flush));
+        assert!(!rank.at_least(HandCategory::FullHouse));
+        assert!(!rank.at_least(HandCategory::StraightFlush));
+    }
+
+    #[test]
+    fn wheel_straight() {
+        let wheel = high_evaluator::evaluate_hand(&Card::vec_from_str("As2d3c4h5s").unwrap())
+            .expect("Evaluation failed");
+        let steel_wheel = high_evaluator::evaluate_hand(&Card::vec_from_str("Ad2d3d4d5d").unwrap())
+            .expect("Evaluation failed");
+        let six_high = high_evaluator::evaluate_hand(&Card::vec_from_str("6s2d3c4h5s").unwrap())
+            .expect("Evaluation failed");
+        let high_card = high_evaluator::evaluate_hand(&Card::vec_from_str("7s2d3c4h5s").unwrap())
+            .expect("Evaluation failed");
+
+        assert!(wheel.is_wheel_straight());
+        assert!(steel_wheel.is_wheel_straight());
+        assert!(!six_high.is_wheel_straight());
+        assert!(!high_card.is_wheel_straight());
+    }
+
+    #[test]
+    fn hands_stronger_bounds() {
+        let royal_flush = high_evaluator::evaluate_hand(&Card::vec_from_str("AsKsQsJsTs").unwrap())
+            .expect("Evaluation failed");
+        let worst_high_card =
+            high_evaluator::evaluate_hand(&Card::vec_from_str("7s5d4c3h2s").unwrap())
+                .expect("Evaluation failed");
+
+        assert_eq!(royal_flush.hands_stronger(), Some(0));
+        assert_eq!(worst_high_card.hands_stronger(), Some(7461));
+
+        assert_eq!(royal_flush.percentile(), Some(100.0));
+        assert_eq!(worst_high_card.percentile(), Some(0.0));
+    }
+
+    #[test]
+    fn hands_stronger_rejects_partial_rank() {
+        let aces = high_evaluator::evaluate_partial(&Card::vec_from_str("AsAd").unwrap())
+            .expect("Evaluation failed");
+        assert!(aces.strength > DISTINCT_HIGH_RANKS);
+
+        assert_eq!(aces.hands_stronger(), None);
+        assert_eq!(aces.percentile(), None);
+    }
+
+    #[test]
+    fn percentile_ordering() {
+        let trips = high_evaluator::evaluate_hand(&Card::vec_from_str("8s8d8c3h2s").unwrap())
+            .expect("Evaluation failed");
+        let flush = high_evaluator::evaluate_hand(&Card::vec_from_str("Js8s6s3s2s").unwrap())
+            .expect("Evaluation failed");
+
+        assert!(flush.hands_stronger() < trips.hands_stronger());
+        assert!(flush.percentile() > trips.percentile());
+    }
+}
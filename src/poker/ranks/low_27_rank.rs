@@ -1,11 +1,76 @@
 use std::ops::Deref;
 
-use super::{BasicRank, IntoRankStrengthIterator, RankStrengthIterator};
+use itertools::Itertools;
+use num_traits::FromPrimitive;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{BasicRank, IntoRankStrengthIterator, LowRank, RankStrengthIterator};
+use crate::{core::Value, poker::evaluators::high_evaluator};
 
 /// A rank of a 2-to-7 lowball hand
+///
+/// With the `serde` feature enabled, this struct also implements serde's `Serialize` and
+/// `Deserialize` traits.
 #[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Low27Rank(pub BasicRank);
 
+impl Low27Rank {
+    /// Regenerates the description of the rank in lowball terms
+    ///
+    /// Since 2-7 lowball hands are compared card by card, a hand without a pair is described by
+    /// listing every card from highest to lowest (e.g. "7-5-4-3-2" rather than "7 High"). Made
+    /// hands keep their usual names (e.g. "7 High Flush"), as the strength of the rank already
+    /// accounts for them being bad lowball hands. The description is generated from the
+    /// `hand_rank` and `sub_rank`, so this also fills in a rank constructed without one.
+    pub fn regenerate_description(&mut self) {
+        let description = match self.hand_rank {
+            1 => no_pair_description(self.sub_rank),
+            _ => high_evaluator::get_string(self.hand_rank, self.sub_rank),
+        };
+        self.0.description = Some(description.unwrap_or_else(|err_str| err_str.to_string()));
+    }
+}
+
+const NO_PAIR_COUNT: usize = 1277;
+
+// colex ordering of every set of 5 distinct values, excluding the straights
+static NO_PAIR_MASKS: [u16; NO_PAIR_COUNT] = no_pair_masks();
+
+const fn no_pair_masks() -> [u16; NO_PAIR_COUNT] {
+    const WHEEL_MASK: u16 = 0x100f;
+    let mut masks = [0u16; NO_PAIR_COUNT];
+    let mut count = 0;
+    let mut mask: u16 = 0;
+    while count < NO_PAIR_COUNT {
+        let mut is_straight = mask == WHEEL_MASK;
+        let mut i = 0;
+        while i < 9 {
+            is_straight |= mask == 0x1f << i;
+            i += 1;
+        }
+        if mask.count_ones() == 5 && !is_straight {
+            masks[count] = mask;
+            count += 1;
+        }
+        mask += 1;
+    }
+    masks
+}
+
+fn no_pair_description(sub_rank: u16) -> Result<String, &'static str> {
+    let mask = NO_PAIR_MASKS
+        .get((sub_rank as usize).wrapping_sub(1))
+        .ok_or("Sub rank for high card was not valid")?;
+
+    Ok((0..13)
+        .rev()
+        .filter(|&value| mask & (1 << value) != 0)
+        .map(|value| Value::from_u8(value).unwrap().get_char())
+        .join("-"))
+}
+
 impl Deref for Low27Rank {
     type Target = BasicRank;
     fn deref(&self) -> &Self::Target {
@@ -18,3 +83,48 @@ impl IntoRankStrengthIterator for Low27Rank {
         RankStrengthIterator::from(self.strength)
     }
 }
+
+impl LowRank for Low27Rank {
+    // the high hand strength is already inverted by the evaluator
+    fn lo_strength(&self) -> u32 {
+        self.strength
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regenerate_missing_description() {
+        let mut rank = Low27Rank(BasicRank {
+            strength: 7462,
+            hand_rank: 1,
+            sub_rank: 1,
+            description: None,
+        });
+
+        rank.regenerate_description();
+        assert_eq!(rank.description.as_ref().unwrap(), "7-5-4-3-2");
+
+        rank.0.hand_rank = 6;
+        rank.regenerate_description();
+        assert_eq!(rank.description.as_ref().unwrap(), "7 High Flush");
+    }
+
+    #[test]
+    fn regenerate_invalid_sub_rank() {
+        let mut rank = Low27Rank(BasicRank {
+            strength: 1,
+            hand_rank: 1,
+            sub_rank: 1278,
+            description: None,
+        });
+
+        rank.regenerate_description();
+        assert_eq!(
+            rank.description.as_ref().unwrap(),
+            "Sub rank for high card was not valid"
+        );
+    }
+}
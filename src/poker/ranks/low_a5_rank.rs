@@ -1,9 +1,21 @@
+use std::cmp::Ordering;
 use std::ops::Deref;
 
-use crate::poker::ranks::{BasicRank, IntoRankStrengthIterator, RankStrengthIterator};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::poker::ranks::{BasicRank, IntoRankStrengthIterator, LowRank, RankStrengthIterator};
 
 /// A rank of a Ace-to-5 lowball hand
+///
+/// This ranks the qualifying 8-or-better lo hands of Omaha Hi-Lo, where the `strength` ranges
+/// from 1 (8-7-6-5-4) to 56 (5-4-3-2-A). Its `lo_strength()` is placed onto the `RazzRank` scale,
+/// so it can be compared against ranks from the Ace-to-5 low evaluator.
+///
+/// With the `serde` feature enabled, this struct also implements serde's `Serialize` and
+/// `Deserialize` traits.
 #[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LowA5Rank(pub BasicRank);
 
 impl Deref for LowA5Rank {
@@ -17,3 +29,76 @@ impl IntoRankStrengthIterator for LowA5Rank {
         RankStrengthIterator::from(self.strength)
     }
 }
+
+// the qualifying lo hands are the 56 best of the 6175 Ace-to-5 low hands ranked by `RazzRank`
+const RAZZ_STRENGTH_OFFSET: u32 = 6175 - 56;
+
+impl LowRank for LowA5Rank {
+    fn lo_strength(&self) -> u32 {
+        self.strength + RAZZ_STRENGTH_OFFSET
+    }
+}
+
+/// Compares two optional lo ranks, treating a missing lo rank as the worst possible lo
+///
+/// In games like Omaha Hi-Lo, not every hand qualifies for a lo. Any qualifying lo (`Some`) beats
+/// a hand without one (`None`), two hands without a lo are considered equal, and two qualifying
+/// lo ranks are compared by strength. Use this rather than relying on the implicit ordering of
+/// `Option`.
+///
+/// ## Example
+/// ```rust
+/// use std::cmp::Ordering;
+/// use playing_cards::{core::Card, poker::{evaluators::omaha_hilo_evaluator, ranks::compare_lo}};
+///
+/// let board = Card::vec_from_str("8hTcAdQs6s").unwrap();
+/// let hero_ranks = omaha_hilo_evaluator::evaluate_hand(&Card::vec_from_str("5s6c9s7c").unwrap(), &board).unwrap();
+/// let villan_ranks = omaha_hilo_evaluator::evaluate_hand(&Card::vec_from_str("AhKdAsTh").unwrap(), &board).unwrap();
+///
+/// assert_eq!(compare_lo(&hero_ranks.lo_rank, &villan_ranks.lo_rank), Ordering::Greater);
+/// ```
+pub fn compare_lo(a: &Option<LowA5Rank>, b: &Option<LowA5Rank>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.cmp(b),
+        (Some(_), None) => Ordering::Greater,
+        (None, Some(_)) => Ordering::Less,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lo_rank(strength: u32) -> LowA5Rank {
+        LowA5Rank(BasicRank {
+            strength,
+            hand_rank: 1,
+            sub_rank: 1,
+            description: None,
+        })
+    }
+
+    #[test]
+    fn some_lo_beats_no_lo() {
+        let worst_lo = Some(lo_rank(1));
+
+        assert_eq!(compare_lo(&worst_lo, &None), Ordering::Greater);
+        assert_eq!(compare_lo(&None, &worst_lo), Ordering::Less);
+    }
+
+    #[test]
+    fn no_lo_ties_no_lo() {
+        assert_eq!(compare_lo(&None, &None), Ordering::Equal);
+    }
+
+    #[test]
+    fn compare_two_lo_ranks() {
+        let wheel = Some(lo_rank(56));
+        let eight_low = Some(lo_rank(1));
+
+        assert_eq!(compare_lo(&wheel, &eight_low), Ordering::Greater);
+        assert_eq!(compare_lo(&eight_low, &wheel), Ordering::Less);
+        assert_eq!(compare_lo(&wheel, &wheel.clone()), Ordering::Equal);
+    }
+}
@@ -0,0 +1,125 @@
+/// A rank of a lowball hand
+///
+/// Lowball rank types do not all derive their strengths in the same way (2-7 lowball ranks are
+/// derived from inverting high hand strengths, while Ace-to-5 ranks are calculated directly), so
+/// this trait exposes a strength with a single orientation across all of them: a higher
+/// `lo_strength` is always a better low hand.
+///
+/// Strengths are only comparable between ranks of the same lowball variant, since each variant
+/// uses its own scale (e.g. a 2-7 lowball strength says nothing about an Ace-to-5 strength). A
+/// `LowA5Rank` only ranks the 56 qualifying Omaha Hi-Lo lo hands, so its `lo_strength` is placed
+/// onto the `RazzRank` scale, which lets Ace-to-5 lows from both evaluators be compared directly.
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::{
+///     core::Card,
+///     poker::{evaluators::{low_27_evaluator, low_a5_evaluator}, ranks::LowRank},
+/// };
+///
+/// let seven_low = low_27_evaluator::evaluate_hand(&Card::vec_from_str("7s5d4c3h2s").unwrap()).unwrap();
+/// let eight_low = low_27_evaluator::evaluate_hand(&Card::vec_from_str("8s5d4c3h2s").unwrap()).unwrap();
+/// let wheel = low_a5_evaluator::evaluate_hand(&Card::vec_from_str("5s4d3c2hAs").unwrap()).unwrap();
+/// let six_low = low_a5_evaluator::evaluate_hand(&Card::vec_from_str("6s4d3c2hAs").unwrap()).unwrap();
+///
+/// assert!(seven_low.lo_strength() > eight_low.lo_strength());
+/// assert!(wheel.lo_strength() > six_low.lo_strength());
+/// ```
+pub trait LowRank {
+    /// Returns the strength of the low hand, where a higher strength is a better low hand
+    fn lo_strength(&self) -> u32;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Card, Suit, Value};
+    use crate::poker::evaluators::{low_27_evaluator, low_a5_evaluator, omaha_hilo_evaluator};
+    use itertools::Itertools;
+    use num_traits::FromPrimitive;
+    use strum::IntoEnumIterator;
+
+    /// Returns every hand of 5 distinct values, where no hand is a flush
+    fn all_unpaired_hands() -> Vec<Vec<Card>> {
+        let suits: Vec<Suit> = Suit::iter().collect();
+        (0..13)
+            .combinations(5)
+            .map(|values| {
+                values
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, value)| Card {
+                        value: Value::from_u8(value).unwrap(),
+                        suit: suits[i % 2],
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn best_27_low_is_strongest() {
+        let best = low_27_evaluator::evaluate_hand(&Card::vec_from_str("7s5d4c3h2s").unwrap())
+            .expect("Evaluation failed");
+
+        for hand in all_unpaired_hands() {
+            let rank = low_27_evaluator::evaluate_hand(&hand).expect("Evaluation failed");
+            assert!(
+                best.lo_strength() >= rank.lo_strength(),
+                "7-5-4-3-2 should be at least as strong as {}",
+                rank.description.as_ref().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn wheel_is_strongest_a5_low() {
+        let wheel = low_a5_evaluator::evaluate_hand(&Card::vec_from_str("5s4d3c2hAs").unwrap())
+            .expect("Evaluation failed");
+
+        for hand in all_unpaired_hands() {
+            let rank = low_a5_evaluator::evaluate_hand(&hand).expect("Evaluation failed");
+            assert!(
+                wheel.lo_strength() >= rank.lo_strength(),
+                "5-4-3-2-A should be at least as strong as {}",
+                rank.description.as_ref().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn lo_strength_orders_like_lowball() {
+        let paired_27 =
+            low_27_evaluator::evaluate_hand(&Card::vec_from_str("2s2d4c5h7s").unwrap()).unwrap();
+        let king_low_27 =
+            low_27_evaluator::evaluate_hand(&Card::vec_from_str("Ks5d4c3h2s").unwrap()).unwrap();
+        let paired_a5 =
+            low_a5_evaluator::evaluate_hand(&Card::vec_from_str("AsAd2c3h4s").unwrap()).unwrap();
+        let king_low_a5 =
+            low_a5_evaluator::evaluate_hand(&Card::vec_from_str("KsQdJcTh9s").unwrap()).unwrap();
+
+        assert!(king_low_27.lo_strength() > paired_27.lo_strength());
+        assert!(king_low_a5.lo_strength() > paired_a5.lo_strength());
+    }
+
+    #[test]
+    fn omaha_hilo_lo_compares_with_a5_low() {
+        let player_hand = Card::vec_from_str("As2dKhKc").unwrap();
+        let board = Card::vec_from_str("3c4d8sKsQd").unwrap();
+        let omaha_lo = omaha_hilo_evaluator::evaluate_hand(&player_hand, &board)
+            .unwrap()
+            .lo_rank
+            .expect("Hand should have a lo");
+
+        let same_low =
+            low_a5_evaluator::evaluate_hand(&Card::vec_from_str("8s4d3cAs2d").unwrap()).unwrap();
+        let seven_low =
+            low_a5_evaluator::evaluate_hand(&Card::vec_from_str("7s4d3cAs2d").unwrap()).unwrap();
+        let nine_low =
+            low_a5_evaluator::evaluate_hand(&Card::vec_from_str("9s4d3cAs2d").unwrap()).unwrap();
+
+        assert_eq!(omaha_lo.lo_strength(), same_low.lo_strength());
+        assert!(seven_low.lo_strength() > omaha_lo.lo_strength());
+        assert!(omaha_lo.lo_strength() > nine_low.lo_strength());
+    }
+}
@@ -4,12 +4,27 @@ pub use basic_rank::*;
 mod high_rank;
 pub use high_rank::*;
 
+mod hand_category;
+pub use hand_category::*;
+
+mod structured_hand;
+pub use structured_hand::*;
+
 mod low_27_rank;
 pub use low_27_rank::*;
 
 mod low_a5_rank;
 pub use low_a5_rank::*;
 
+mod razz_rank;
+pub use razz_rank::*;
+
+mod short_deck_rank;
+pub use short_deck_rank::*;
+
+mod low_rank;
+pub use low_rank::*;
+
 mod omaha_hilo_rank;
 pub use omaha_hilo_rank::*;
 
@@ -19,6 +34,9 @@ pub use dramaha_high_rank::*;
 mod badugi_rank;
 pub use badugi_rank::*;
 
+mod game_rank;
+pub use game_rank::*;
+
 mod rank_strength_iterator;
 pub use rank_strength_iterator::*;
 
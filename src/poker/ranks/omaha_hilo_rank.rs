@@ -1,13 +1,21 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use super::{HighRank, IntoRankStrengthIterator, LowA5Rank, RankStrengthIterator};
 
 /// A struct of for a given Omaha Hi-Lo hand
+///
+/// With the `serde` feature enabled, this struct also implements serde's `Serialize` and
+/// `Deserialize` traits.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OmahaHiLoRank {
     /// The Omaha hi rank from the hand
     pub hi_rank: HighRank,
     /// The Omaha lo rank from the hand
     ///
-    /// Note that not all Omaha hands + boards will result in a lo hand.
+    /// Note that not all Omaha hands + boards will result in a lo hand. With the `serde` feature
+    /// enabled, a missing lo rank is serialized as a none value (e.g. `null` in JSON).
     pub lo_rank: Option<LowA5Rank>,
 }
 
@@ -19,3 +27,51 @@ impl IntoRankStrengthIterator for OmahaHiLoRank {
         ])
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::core::Card;
+    use crate::poker::evaluators::{dramaha_high_evaluator, omaha_hilo_evaluator};
+    use crate::poker::ranks::DramahaHighRank;
+
+    fn round_trip<T: Serialize + for<'de> Deserialize<'de>>(rank: &T) -> T {
+        let json = serde_json::to_string(rank).expect("Rank could not be serialized");
+        serde_json::from_str(&json).expect("Rank could not be deserialized")
+    }
+
+    #[test]
+    fn omaha_hilo_rank_round_trip_with_lo() {
+        let player_hand = Card::vec_from_str("As2dKhKc").expect("Failed parsing card string");
+        let board = Card::vec_from_str("3c4d8sKsQd").expect("Failed parsing card string");
+        let rank =
+            omaha_hilo_evaluator::evaluate_hand(&player_hand, &board).expect("Evaluation failed");
+
+        assert!(rank.lo_rank.is_some());
+        assert_eq!(round_trip(&rank), rank);
+    }
+
+    #[test]
+    fn omaha_hilo_rank_round_trip_without_lo() {
+        let player_hand = Card::vec_from_str("As2dKhKc").expect("Failed parsing card string");
+        let board = Card::vec_from_str("3c9dTsKsQd").expect("Failed parsing card string");
+        let rank =
+            omaha_hilo_evaluator::evaluate_hand(&player_hand, &board).expect("Evaluation failed");
+
+        assert_eq!(rank.lo_rank, None);
+        assert!(serde_json::to_string(&rank)
+            .expect("Rank could not be serialized")
+            .contains(r#""lo_rank":null"#));
+        assert_eq!(round_trip(&rank), rank);
+    }
+
+    #[test]
+    fn dramaha_high_rank_round_trip() {
+        let player_hand = Card::vec_from_str("AsAdKhKcQs").expect("Failed parsing card string");
+        let board = Card::vec_from_str("3c4d8sKsQd").expect("Failed parsing card string");
+        let rank: DramahaHighRank =
+            dramaha_high_evaluator::evaluate_hand(&player_hand, &board).expect("Evaluation failed");
+
+        assert_eq!(round_trip(&rank), rank);
+    }
+}
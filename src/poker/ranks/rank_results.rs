@@ -18,6 +18,7 @@ use super::IntoRankStrengthIterator;
 #[derive(Debug, Clone)]
 pub struct RankResults<T: Hash + Eq + Copy> {
     hand_table: BTreeMap<usize, Vec<HashSet<T>>>,
+    strength_table: Option<BTreeMap<usize, Vec<u32>>>,
 }
 
 impl<T: Hash + Eq + Copy> RankResults<T> {
@@ -26,7 +27,25 @@ impl<T: Hash + Eq + Copy> RankResults<T> {
     /// This does not do any other post-computation. This is typically called within the
     /// `generate_winner_list()` functions.
     pub fn new(hand_table: BTreeMap<usize, Vec<HashSet<T>>>) -> Self {
-        Self { hand_table }
+        Self {
+            hand_table,
+            strength_table: None,
+        }
+    }
+
+    /// Generates a new `RankResults<T>` that retains the strength of each set of keys.
+    ///
+    /// Each list of strengths in `strength_table` is expected to be parallel to the list of
+    /// hashsets of the same hand number in `hand_table`. This is typically called within the
+    /// `generate_winner_list_with_strengths()` function.
+    pub fn with_strengths(
+        hand_table: BTreeMap<usize, Vec<HashSet<T>>>,
+        strength_table: BTreeMap<usize, Vec<u32>>,
+    ) -> Self {
+        Self {
+            hand_table,
+            strength_table: Some(strength_table),
+        }
     }
 
     /// Gets the specified hand number if it exists.
@@ -36,9 +55,51 @@ impl<T: Hash + Eq + Copy> RankResults<T> {
     pub fn hand_number(&self, n: usize) -> Option<&Vec<HashSet<T>>> {
         self.hand_table.get(&n)
     }
+
+    /// Gets the strengths of the specified hand number if they were retained.
+    ///
+    /// The list is parallel to the list returned by `hand_number(&self, n)`, so index 0 is the
+    /// strength shared by the keys with the strongest rank. None is returned if the strengths were
+    /// not retained or the index does not exist.
+    pub fn strengths(&self, n: usize) -> Option<&Vec<u32>> {
+        self.strength_table.as_ref()?.get(&n)
+    }
+
+    /// Returns the strength gap between the winning keys and the runner-up keys of hand number 0
+    ///
+    /// None is returned if the strengths were not retained (see
+    /// `generate_winner_list_with_strengths()`) or there are less than two distinct ranks within
+    /// hand number 0.
+    pub fn winning_margin(&self) -> Option<u32> {
+        match self.strengths(0)?.as_slice() {
+            [winner, runner_up, ..] => Some(winner - runner_up),
+            _ => None,
+        }
+    }
+
+    /// Flattens the results of hand number 0 into a list of placements and keys
+    ///
+    /// Each key is paired with its 1-based placement using standard competition ranking, where
+    /// tied keys share the same placement and the following placement is skipped accordingly
+    /// (e.g. two keys tied for first are followed by a key in third). The list is sorted by
+    /// placement, but the order of tied keys is not guaranteed. An empty vector is returned if
+    /// there is no hand number 0.
+    ///
+    /// This is intended for results containing a single hand, such as the results produced by
+    /// `rank_field()`.
+    pub fn flat_placements(&self) -> Vec<(usize, T)> {
+        let mut placement = 1;
+        let mut placements = Vec::new();
+        for tied_keys in self.hand_number(0).into_iter().flatten() {
+            placements.extend(tied_keys.iter().map(|&key| (placement, key)));
+            placement += tied_keys.len();
+        }
+
+        placements
+    }
 }
 
-impl<'a, T: Hash + Eq + Copy> IntoIterator for &'a RankResults<T> {
+impl<T: Hash + Eq + Copy> IntoIterator for &RankResults<T> {
     type Item = (usize, Vec<HashSet<T>>);
     type IntoIter = std::collections::btree_map::IntoIter<usize, Vec<HashSet<T>>>;
 
@@ -55,6 +116,60 @@ impl<'a, T: Hash + Eq + Copy> IntoIterator for &'a RankResults<T> {
 /// A `RankResults<T>` type is returned. This can then be used to view individual hand and can be
 /// iterated across.
 pub fn generate_winner_list<T, U>(ranks: &HashMap<T, U>) -> RankResults<T>
+where
+    T: Eq + Hash + Copy,
+    U: IntoRankStrengthIterator + Clone,
+{
+    let table = rank_strength_table(ranks)
+        .into_iter()
+        .map(|(i, ranking_list)| (i, ranking_list.into_iter().map(|(keys, _)| keys).collect()))
+        .collect();
+
+    RankResults::new(table)
+}
+
+/// Generates a vector of type T which can be used to direct who is the winner, retaining the
+/// strength of each rank.
+///
+/// This behaves identically to `generate_winner_list()`, but the returned `RankResults<T>` also
+/// keeps the strength shared by each set of keys. This allows for queries such as
+/// `winning_margin()`.
+///
+/// ## Example
+/// ```rust
+/// use std::collections::HashMap;
+/// use playing_cards::{core::Card, poker::{evaluators::high_evaluator, ranks::generate_winner_list_with_strengths}};
+///
+/// let board = Card::vec_from_str("2h5dTcJsAs").unwrap();
+/// let ranks = HashMap::from([
+///     ("hero", high_evaluator::evaluate_hand(&[Card::vec_from_str("AhAc").unwrap(), board.clone()].concat()).unwrap()),
+///     ("villain", high_evaluator::evaluate_hand(&[Card::vec_from_str("AdKc").unwrap(), board.clone()].concat()).unwrap()),
+/// ]);
+///
+/// let results = generate_winner_list_with_strengths(&ranks);
+///
+/// assert_eq!(
+///     results.winning_margin(),
+///     Some(ranks["hero"].strength - ranks["villain"].strength)
+/// );
+/// ```
+pub fn generate_winner_list_with_strengths<T, U>(ranks: &HashMap<T, U>) -> RankResults<T>
+where
+    T: Eq + Hash + Copy,
+    U: IntoRankStrengthIterator + Clone,
+{
+    let (hand_table, strength_table) = rank_strength_table(ranks)
+        .into_iter()
+        .map(|(i, ranking_list)| {
+            let (keys, strengths): (Vec<_>, Vec<_>) = ranking_list.into_iter().unzip();
+            ((i, keys), (i, strengths))
+        })
+        .unzip();
+
+    RankResults::with_strengths(hand_table, strength_table)
+}
+
+fn rank_strength_table<T, U>(ranks: &HashMap<T, U>) -> BTreeMap<usize, Vec<(HashSet<T>, u32)>>
 where
     T: Eq + Hash + Copy,
     U: IntoRankStrengthIterator + Clone,
@@ -86,7 +201,7 @@ where
         })
         .collect();
 
-    let table: BTreeMap<usize, _> = transpose
+    transpose
         .iter()
         .enumerate()
         .map(|(i, ranks)| {
@@ -100,24 +215,63 @@ where
                 return (i, vec![]);
             }
 
-            let mut ranking_list = vec![HashSet::from([*sorted_ranks_desc[0].0])];
+            let (first_id, first_rank) = sorted_ranks_desc[0];
+            let mut ranking_list = vec![(HashSet::from([*first_id]), *first_rank)];
             let _: () = sorted_ranks_desc
                 .windows(2)
                 .flat_map(<&[(&T, &u32); 2]>::try_from)
                 .map(|&[(_, prev_rank), (curr_id, curr_rank)]| {
                     if prev_rank == curr_rank {
-                        ranking_list.last_mut().unwrap().insert(*curr_id);
+                        ranking_list.last_mut().unwrap().0.insert(*curr_id);
                     } else {
-                        ranking_list.push(HashSet::from([*curr_id]));
+                        ranking_list.push((HashSet::from([*curr_id]), *curr_rank));
                     }
                 })
                 .collect();
 
             (i, ranking_list)
         })
-        .collect();
+        .collect()
+}
 
-    RankResults::new(table)
+/// Ranks a field of players, generating a separate `RankResults<T>` for each strength component
+///
+/// Each player's rank is converted into a `RankStrengthIterator`, and the n-th strength of every
+/// player is ranked independently of the others (e.g. for Omaha Hi-Lo, index 0 contains the hi
+/// results and index 1 contains the lo results). Every returned `RankResults<T>` only contains
+/// hand number 0. Players without a rank for a given component (e.g. no qualifying lo hand) are
+/// left out of that component's results.
+///
+/// Like `generate_winner_list()`, the keys are expected to be unique. If a key is repeated, only
+/// the last rank for the key is used. An empty vector is returned if there are no players.
+///
+/// ## Example
+/// ```rust
+/// use std::collections::HashSet;
+/// use playing_cards::{core::Card, poker::{evaluators::high_evaluator, ranks::rank_field}};
+///
+/// let board = Card::vec_from_str("2h5dTcJsAs").unwrap();
+/// let players = [
+///     ("hero", high_evaluator::evaluate_hand(&[Card::vec_from_str("AhAc").unwrap(), board.clone()].concat()).unwrap()),
+///     ("villain", high_evaluator::evaluate_hand(&[Card::vec_from_str("KdKc").unwrap(), board.clone()].concat()).unwrap()),
+/// ];
+///
+/// let results = rank_field(&players);
+///
+/// assert_eq!(results.len(), 1);
+/// assert_eq!(results[0].hand_number(0).unwrap()[0], HashSet::from(["hero"]));
+/// ```
+pub fn rank_field<T, R>(players: &[(T, R)]) -> Vec<RankResults<T>>
+where
+    T: Eq + Hash + Copy,
+    R: IntoRankStrengthIterator + Clone,
+{
+    let ranks: HashMap<T, R> = players.iter().cloned().collect();
+
+    generate_winner_list(&ranks)
+        .into_iter()
+        .map(|(_, ranking_list)| RankResults::new(BTreeMap::from([(0, ranking_list)])))
+        .collect()
 }
 
 #[cfg(test)]
@@ -168,9 +322,7 @@ mod tests {
             (0, vec![HashSet::from([1]), HashSet::from([2])]),
             (1, vec![HashSet::from([1])]),
         ]);
-        for (winner_col, expected_winner_col) in rank_results
-            .into_iter()
-            .zip(expected_rank_results.into_iter())
+        for (winner_col, expected_winner_col) in rank_results.into_iter().zip(expected_rank_results)
         {
             assert_eq!(winner_col, expected_winner_col);
         }
@@ -237,9 +389,7 @@ mod tests {
             (1, vec![HashSet::from([1]), HashSet::from([2])]),
         ]);
 
-        for (winner_col, expected_winner_col) in rank_results
-            .into_iter()
-            .zip(expected_rank_results.into_iter())
+        for (winner_col, expected_winner_col) in rank_results.into_iter().zip(expected_rank_results)
         {
             assert_eq!(winner_col, expected_winner_col);
         }
@@ -271,9 +421,7 @@ mod tests {
         let rank_results = generate_winner_list(&ranks);
 
         let expected_rank_results = BTreeMap::from([(0, vec![HashSet::from([1, 2])])]);
-        for (winner_col, expected_winner_col) in rank_results
-            .into_iter()
-            .zip(expected_rank_results.into_iter())
+        for (winner_col, expected_winner_col) in rank_results.into_iter().zip(expected_rank_results)
         {
             assert_eq!(winner_col, expected_winner_col);
         }
@@ -287,11 +435,132 @@ mod tests {
 
         let expected_rank_results: RankResults<i32> = RankResults::new(BTreeMap::new());
 
-        for (winner_col, expected_winner_col) in rank_results
-            .into_iter()
-            .zip(expected_rank_results.into_iter())
+        for (winner_col, expected_winner_col) in
+            rank_results.into_iter().zip(&expected_rank_results)
         {
             assert_eq!(winner_col, expected_winner_col);
         }
     }
+
+    #[test]
+    fn rank_field_omaha_hilo() {
+        use crate::core::Card;
+        use crate::poker::evaluators::omaha_hilo_evaluator;
+
+        let board = Card::vec_from_str("7h6c3s8dKs").unwrap();
+        let players = [
+            // nut low with a pair of queens
+            (1, Card::vec_from_str("As2dQhQd").unwrap()),
+            // 9 high straight (9-5 with 8-7-6) with the second nut low
+            (2, Card::vec_from_str("Ad4c5h9h").unwrap()),
+            // set of kings with no low
+            (3, Card::vec_from_str("KhKcJdTd").unwrap()),
+        ]
+        .map(|(player, hand)| {
+            (
+                player,
+                omaha_hilo_evaluator::evaluate_hand(&hand, &board).expect("Evaluation failed"),
+            )
+        });
+
+        let results = rank_field(&players);
+
+        assert_eq!(results.len(), 2);
+
+        let hi_results = results[0].hand_number(0).unwrap();
+        assert_eq!(
+            hi_results,
+            &vec![HashSet::from([2]), HashSet::from([3]), HashSet::from([1])]
+        );
+
+        let lo_results = results[1].hand_number(0).unwrap();
+        assert_eq!(lo_results, &vec![HashSet::from([1]), HashSet::from([2])]);
+    }
+
+    #[test]
+    fn winning_margin_flush_over_straight() {
+        use crate::core::Card;
+        use crate::poker::evaluators::high_evaluator;
+
+        let board = Card::vec_from_str("9h8h7c2hKd").unwrap();
+        let flush = high_evaluator::evaluate_hand(
+            &[Card::vec_from_str("Ah3h").unwrap(), board.clone()].concat(),
+        )
+        .expect("Evaluation failed");
+        let straight = high_evaluator::evaluate_hand(
+            &[Card::vec_from_str("Ts6d").unwrap(), board.clone()].concat(),
+        )
+        .expect("Evaluation failed");
+        assert_eq!(flush.description.as_ref().unwrap(), "Ace High Flush");
+        assert_eq!(straight.description.as_ref().unwrap(), "10 High Straight");
+
+        let ranks = HashMap::from([(1, flush.clone()), (2, straight.clone())]);
+
+        let rank_results = generate_winner_list_with_strengths(&ranks);
+
+        assert_eq!(
+            rank_results.hand_number(0).unwrap(),
+            &vec![HashSet::from([1]), HashSet::from([2])]
+        );
+        assert_eq!(
+            rank_results.strengths(0).unwrap(),
+            &vec![flush.strength, straight.strength]
+        );
+        assert_eq!(
+            rank_results.winning_margin(),
+            Some(flush.strength - straight.strength)
+        );
+
+        // strengths are not retained without the variant
+        assert_eq!(generate_winner_list(&ranks).winning_margin(), None);
+    }
+
+    #[test]
+    fn winning_margin_without_runner_up() {
+        let ranks = HashMap::from([(
+            1,
+            HighRank(BasicRank {
+                strength: 4321,
+                hand_rank: 5,
+                sub_rank: 12,
+                description: None,
+            }),
+        )]);
+
+        assert_eq!(
+            generate_winner_list_with_strengths(&ranks).winning_margin(),
+            None
+        );
+    }
+
+    #[test]
+    fn rank_field_empty() {
+        let players: [(i32, HighRank); 0] = [];
+
+        assert!(rank_field(&players).is_empty());
+    }
+
+    #[test]
+    fn flat_placements_with_tie() {
+        let rank_results = RankResults::new(BTreeMap::from([(
+            0,
+            vec![
+                HashSet::from([1, 2]),
+                HashSet::from([3]),
+                HashSet::from([4]),
+            ],
+        )]));
+
+        let mut placements = rank_results.flat_placements();
+        placements.sort();
+
+        assert_eq!(placements, vec![(1, 1), (1, 2), (3, 3), (4, 4)]);
+    }
+
+    #[test]
+    fn flat_placements_empty() {
+        let rank_results: RankResults<i32> = RankResults::new(BTreeMap::new());
+
+        assert!(rank_results.flat_placements().is_empty());
+    }
 }
@@ -0,0 +1,37 @@
+use std::ops::Deref;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{BasicRank, IntoRankStrengthIterator, LowRank, RankStrengthIterator};
+
+/// A rank of an Ace-to-5 lowball hand without a qualifier (e.g. Razz)
+///
+/// Unlike a `LowA5Rank`, which only ranks the 56 qualifying lo hands of Omaha Hi-Lo, every hand
+/// has a `RazzRank`. The `strength` ranges from 1 (King quads with a Queen) to 6175 (5-4-3-2-A),
+/// where a higher strength is a better low hand.
+///
+/// With the `serde` feature enabled, this struct also implements serde's `Serialize` and
+/// `Deserialize` traits.
+#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RazzRank(pub BasicRank);
+
+impl Deref for RazzRank {
+    type Target = BasicRank;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl IntoRankStrengthIterator for RazzRank {
+    fn into_strength_iter(self) -> RankStrengthIterator {
+        RankStrengthIterator::from(self.strength)
+    }
+}
+
+impl LowRank for RazzRank {
+    fn lo_strength(&self) -> u32 {
+        self.strength
+    }
+}
@@ -0,0 +1,32 @@
+use std::ops::Deref;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{BasicRank, IntoRankStrengthIterator, RankStrengthIterator};
+
+/// A rank of a short deck (6+ Hold'em) high hand
+///
+/// The `hand_rank`, `sub_rank`, and `description` are identical to those of a `HighRank`, but the
+/// `strength` is ordered by the short deck hand rankings, where a flush beats a full house and
+/// trips beat a straight. The `strength` ranges from 1 (7-5-4-3-2) to 7462 (a royal flush), so a
+/// `ShortDeckRank` should never be compared against a `HighRank`.
+///
+/// With the `serde` feature enabled, this struct also implements serde's `Serialize` and
+/// `Deserialize` traits.
+#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ShortDeckRank(pub BasicRank);
+
+impl Deref for ShortDeckRank {
+    type Target = BasicRank;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl IntoRankStrengthIterator for ShortDeckRank {
+    fn into_strength_iter(self) -> RankStrengthIterator {
+        RankStrengthIterator::from(self.strength)
+    }
+}
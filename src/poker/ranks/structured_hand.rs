@@ -0,0 +1,35 @@
+use crate::core::Value;
+
+use super::{HandCategory, HighRank};
+
+/// A structured breakdown of a made high hand
+///
+/// Rather than having to parse the description of a rank, the values that make up the hand are
+/// stored in order of significance. `primary_values` contains the values that define the hand
+/// category and `kickers` contains the remaining values used to break ties:
+///
+/// | Category       | `primary_values`          | `kickers`        |
+/// |----------------|---------------------------|------------------|
+/// | High Card      | Highest card              | Other 4 cards    |
+/// | Pair           | Pair                      | Other 3 cards    |
+/// | Two Pair       | Higher pair, lower pair   | Other card       |
+/// | Trips          | Trips                     | Other 2 cards    |
+/// | Straight       | Highest card of straight  | None             |
+/// | Flush          | All 5 cards               | None             |
+/// | Full House     | Trips, pair               | None             |
+/// | Quads          | Quads                     | Other card       |
+/// | Straight Flush | Highest card of straight  | None             |
+///
+/// Kickers are always sorted in descending order. In the case of a 5-high straight (the wheel),
+/// the highest card of the straight is the 5.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct StructuredHand {
+    /// The rank of the hand
+    pub rank: HighRank,
+    /// The category of the hand
+    pub category: HandCategory,
+    /// The values that define the hand category, most significant first
+    pub primary_values: Vec<Value>,
+    /// The remaining values of the hand, most significant first
+    pub kickers: Vec<Value>,
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use crate::core::{Card, Suit, Value};
+
+/// Counts how many times each value appears within the cards
+///
+/// Values that do not appear within the cards are not included in the map. This is the basis of
+/// detecting pairs, trips, and quads.
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::{core::{Card, Value}, poker::stats};
+///
+/// let counts = stats::value_counts(&Card::vec_from_str("KsKd2c").unwrap());
+///
+/// assert_eq!(counts[&Value::King], 2);
+/// assert_eq!(counts[&Value::Two], 1);
+/// assert_eq!(counts.get(&Value::Ace), None);
+/// ```
+pub fn value_counts(cards: &[Card]) -> HashMap<Value, usize> {
+    let mut counts = HashMap::new();
+    for card in cards {
+        *counts.entry(card.value).or_insert(0) += 1;
+    }
+
+    counts
+}
+
+/// Counts how many times each suit appears within the cards
+///
+/// Suits that do not appear within the cards are not included in the map. This is the basis of
+/// detecting flushes and flush draws.
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::{core::{Card, Suit}, poker::stats};
+///
+/// let counts = stats::suit_counts(&Card::vec_from_str("AsKs2c").unwrap());
+///
+/// assert_eq!(counts[&Suit::Spade], 2);
+/// assert_eq!(counts[&Suit::Club], 1);
+/// ```
+pub fn suit_counts(cards: &[Card]) -> HashMap<Suit, usize> {
+    let mut counts = HashMap::new();
+    for card in cards {
+        *counts.entry(card.suit).or_insert(0) += 1;
+    }
+
+    counts
+}
+
+/// Groups the cards by value, returning each value with the number of cards of that value
+///
+/// Groups are sorted by count in descending order, and groups with the same count are sorted by
+/// value in descending order (i.e. Aces are highest). For example, a full house of 9s full of 4s
+/// will return `[(Nine, 3), (Four, 2)]`. This is useful for lowball games where cards that share
+/// a value are penalized.
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::{core::{Card, Value}, poker::stats};
+///
+/// let groups = stats::rank_groups(&Card::vec_from_str("2s3dKc2hAs").unwrap());
+///
+/// assert_eq!(
+///     groups,
+///     vec![(Value::Two, 2), (Value::Ace, 1), (Value::King, 1), (Value::Three, 1)]
+/// );
+/// ```
+pub fn rank_groups(cards: &[Card]) -> Vec<(Value, usize)> {
+    let mut groups: Vec<(Value, usize)> = value_counts(cards).into_iter().collect();
+    groups.sort_by(|(a_value, a_count), (b_value, b_count)| {
+        b_count.cmp(a_count).then(b_value.cmp(a_value))
+    });
+
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_house_value_counts() {
+        let cards = Card::vec_from_str("9s9d9h4c4s").unwrap();
+
+        let counts = value_counts(&cards);
+
+        assert_eq!(counts, HashMap::from([(Value::Nine, 3), (Value::Four, 2)]));
+    }
+
+    #[test]
+    fn flush_suit_counts() {
+        let cards = Card::vec_from_str("2h5h9hJhKhAs").unwrap();
+
+        let counts = suit_counts(&cards);
+
+        assert_eq!(counts[&Suit::Heart], 5);
+        assert_eq!(counts[&Suit::Spade], 1);
+        assert_eq!(counts.len(), 2);
+    }
+
+    #[test]
+    fn empty_counts() {
+        assert!(value_counts(&[]).is_empty());
+        assert!(suit_counts(&[]).is_empty());
+    }
+
+    #[test]
+    fn pair_and_trips_rank_groups() {
+        let cards = Card::vec_from_str("3s3d3hKcKsAd7c").unwrap();
+
+        let groups = rank_groups(&cards);
+
+        assert_eq!(
+            groups,
+            vec![
+                (Value::Three, 3),
+                (Value::King, 2),
+                (Value::Ace, 1),
+                (Value::Seven, 1)
+            ]
+        );
+        assert!(rank_groups(&[]).is_empty());
+    }
+}
@@ -0,0 +1,150 @@
+use crate::core::{Card, CardDeck, Suit};
+
+/// Converts a suit into its stud bring-in order (clubs = 0, diamonds = 1, hearts = 2, spades = 3)
+fn bring_in_suit_order(suit: Suit) -> u8 {
+    match suit {
+        Suit::Club => 0,
+        Suit::Diamond => 1,
+        Suit::Heart => 2,
+        Suit::Spade => 3,
+    }
+}
+
+/// Returns the key of the player who must bring in
+///
+/// In high Stud games, the player showing the lowest upcard brings in, where Aces are high. If
+/// multiple players show the same value, the suit breaks the tie in alphabetical order, with clubs
+/// being the lowest followed by diamonds, hearts, and then spades. None is returned if there are
+/// no upcards.
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::{core::Card, poker::stud};
+///
+/// let upcards = [
+///     ("hero", Card::vec_from_str("Ks").unwrap()[0]),
+///     ("villain", Card::vec_from_str("3h").unwrap()[0]),
+/// ];
+///
+/// assert_eq!(stud::bring_in(&upcards), Some("villain"));
+/// ```
+pub fn bring_in<T: Copy>(upcards: &[(T, Card)]) -> Option<T> {
+    upcards
+        .iter()
+        .min_by_key(|(_, card)| (card.value, bring_in_suit_order(card.suit)))
+        .map(|&(key, _)| key)
+}
+
+/// A player's hand in a Stud game, separated into face-down and face-up cards
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct StudHand {
+    /// Cards only visible to the player
+    pub down: Vec<Card>,
+    /// Cards visible to all players
+    pub up: Vec<Card>,
+}
+
+impl StudHand {
+    /// Returns all cards of the hand, with the down cards followed by the up cards
+    pub fn cards(&self) -> Vec<Card> {
+        self.down.iter().chain(self.up.iter()).cloned().collect()
+    }
+}
+
+/// Deals `down` face-down cards followed by `up` face-up cards to each player
+///
+/// Cards are dealt one at a time to each player in turn, starting with every down card round
+/// before any up card round, as they would be on the first street of Stud (e.g. `down = 2` and
+/// `up = 1` for Seven Card Stud). The muck is never used, so if there are not enough cards in the
+/// deck to deal every player their hand, None is returned and no cards are dealt.
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::{core::CardDeck, poker::stud};
+///
+/// let mut deck = CardDeck::default();
+///
+/// let hands = stud::deal_stud(&mut deck, 4, 2, 1).unwrap();
+///
+/// assert_eq!(hands.len(), 4);
+/// assert!(hands.iter().all(|hand| hand.down.len() == 2 && hand.up.len() == 1));
+/// assert_eq!(deck.len(), 52 - 12);
+/// ```
+pub fn deal_stud(
+    deck: &mut CardDeck,
+    num_players: usize,
+    down: usize,
+    up: usize,
+) -> Option<Vec<StudHand>> {
+    if !deck.check_deal_cards(num_players * (down + up), false) {
+        return None;
+    }
+
+    let mut hands = vec![StudHand::default(); num_players];
+    for round in 0..down + up {
+        for hand in hands.iter_mut() {
+            let card = deck.deal_cards(1, false)?.pop()?;
+            if round < down {
+                hand.down.push(card);
+            } else {
+                hand.up.push(card);
+            }
+        }
+    }
+
+    Some(hands)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn club_brings_in_on_equal_rank() {
+        let upcards = [
+            (1, Card::vec_from_str("2h").unwrap()[0]),
+            (2, Card::vec_from_str("2c").unwrap()[0]),
+            (3, Card::vec_from_str("2d").unwrap()[0]),
+            (4, Card::vec_from_str("5c").unwrap()[0]),
+        ];
+
+        assert_eq!(bring_in(&upcards), Some(2));
+    }
+
+    #[test]
+    fn lowest_value_brings_in() {
+        let upcards = [
+            (1, Card::vec_from_str("Ac").unwrap()[0]),
+            (2, Card::vec_from_str("9s").unwrap()[0]),
+            (3, Card::vec_from_str("Tc").unwrap()[0]),
+        ];
+
+        assert_eq!(bring_in(&upcards), Some(2));
+        assert_eq!(bring_in::<i32>(&[]), None);
+    }
+
+    #[test]
+    fn deal_two_down_one_up() {
+        let mut deck = CardDeck::default();
+        let expected_order: Vec<Card> = deck.clone().take(12).collect();
+
+        let hands = deal_stud(&mut deck, 4, 2, 1).expect("Not enough cards to deal");
+
+        assert_eq!(hands.len(), 4);
+        for (i, hand) in hands.iter().enumerate() {
+            assert_eq!(hand.down, vec![expected_order[i], expected_order[4 + i]]);
+            assert_eq!(hand.up, vec![expected_order[8 + i]]);
+            assert_eq!(hand.cards().len(), 3);
+        }
+        assert_eq!(deck.dealt_cards(), &expected_order[..]);
+    }
+
+    #[test]
+    fn deal_stud_not_enough_cards() {
+        let mut deck = CardDeck::default();
+
+        // 8 players with 7 cards each would need 56 cards
+        assert_eq!(deal_stud(&mut deck, 8, 3, 4), None);
+        assert_eq!(deck.len(), 52);
+    }
+}
@@ -0,0 +1,143 @@
+use itertools::Itertools;
+
+use crate::core::{BitDeck, Card};
+use crate::poker::{
+    evaluators::{high_evaluator, EvaluatorError},
+    game_kind::check_card_count,
+    GameKind,
+};
+
+/// Returns every Hold'em hole card combination that beats the villain's hand on the board
+///
+/// The hero's hole cards are drawn from all cards not held by the villain or on the board, and a
+/// combination is only included if its high hand is strictly better than the villain's (chops
+/// are excluded). The cards of each combination are ordered from the lowest card to the highest
+/// card as ordered by `Card::to_int()`, and the combinations are returned in the same order.
+///
+/// The villain must hold exactly 2 cards and the board must contain between 3 and 5 cards. If
+/// either are not met, then either a `NotEnoughCards` or a `TooManyCards` will return. If
+/// duplicate cards are found across the villain's hand and the board, then a
+/// `FailedToCalculateRank` error will return.
+///
+/// ## Example
+/// ```rust
+/// use playing_cards::{core::Card, poker::study};
+///
+/// let villain = Card::vec_from_str("KsKc").unwrap();
+/// let board = Card::vec_from_str("7h8h9hKd2s").unwrap();
+///
+/// let holdings = study::winning_holdings(&villain, &board).unwrap();
+///
+/// assert!(holdings.contains(&Card::arr_from_str("TcJd").unwrap()));
+/// assert!(!holdings.contains(&Card::arr_from_str("AcAd").unwrap()));
+/// ```
+pub fn winning_holdings(
+    villain: &[Card],
+    board: &[Card],
+) -> Result<Vec<[Card; 2]>, EvaluatorError> {
+    GameKind::Holdem.validate(villain)?;
+    let (min_board_cards, max_board_cards) = GameKind::Holdem.board_card_bounds();
+    check_card_count("Board", board.len(), min_board_cards, max_board_cards)?;
+
+    let villain_rank = high_evaluator::evaluate_iter(villain.iter().chain(board.iter()).cloned())?;
+
+    let mut remaining_cards = BitDeck::new();
+    remaining_cards.strip(&BitDeck::from_cards(villain));
+    remaining_cards.strip(&BitDeck::from_cards(board));
+
+    remaining_cards
+        .cards()
+        .into_iter()
+        .tuple_combinations()
+        .filter_map(|(low_card, high_card)| {
+            let hero_cards = [low_card, high_card];
+            match high_evaluator::evaluate_iter(hero_cards.iter().chain(board.iter()).cloned()) {
+                Ok(rank) if rank > villain_rank => Some(Ok(hero_cards)),
+                Ok(_) => None,
+                Err(err) => Some(Err(err)),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn holding(s: &str) -> [Card; 2] {
+        Card::arr_from_str(s).unwrap()
+    }
+
+    fn contains(holdings: &[[Card; 2]], s: &str) -> bool {
+        let [first, second] = holding(s);
+        holdings
+            .iter()
+            .any(|&h| h == [first, second] || h == [second, first])
+    }
+
+    #[test]
+    fn flushes_and_straights_beat_set() {
+        let villain = Card::vec_from_str("KsKc").unwrap();
+        let board = Card::vec_from_str("7h8h9hKd2s").unwrap();
+
+        let holdings = winning_holdings(&villain, &board).unwrap();
+
+        // flushes
+        assert!(contains(&holdings, "AhQh"));
+        assert!(contains(&holdings, "3h4h"));
+        // straights
+        assert!(contains(&holdings, "TcJd"));
+        assert!(contains(&holdings, "5c6d"));
+
+        // weaker hands
+        assert!(!contains(&holdings, "AsAd"));
+        assert!(!contains(&holdings, "2c2d"));
+        assert!(!contains(&holdings, "9s9d"));
+        assert!(!contains(&holdings, "KhAc"));
+        assert!(!contains(&holdings, "7c8c"));
+    }
+
+    #[test]
+    fn holdings_exclude_known_cards() {
+        let villain = Card::vec_from_str("KsKc").unwrap();
+        let board = Card::vec_from_str("7h8h9hKd2s").unwrap();
+        let known_cards = BitDeck::from_cards(&[villain.clone(), board.clone()].concat());
+
+        let holdings = winning_holdings(&villain, &board).unwrap();
+
+        assert!(!holdings.is_empty());
+        for hand in &holdings {
+            assert!(!hand.iter().any(|card| known_cards.contains(card)));
+            assert_ne!(hand[0], hand[1]);
+        }
+    }
+
+    #[test]
+    fn chops_are_excluded() {
+        // the board plays for everyone
+        let villain = Card::vec_from_str("2c3d").unwrap();
+        let board = Card::vec_from_str("AsKsQsJsTs").unwrap();
+
+        assert!(winning_holdings(&villain, &board).unwrap().is_empty());
+    }
+
+    #[test]
+    fn invalid_input() {
+        let villain = Card::vec_from_str("KsKc").unwrap();
+
+        assert_eq!(
+            winning_holdings(&villain, &Card::vec_from_str("7h8h").unwrap()),
+            Err(EvaluatorError::NotEnoughCards {
+                card_set_type: "Board".to_string(),
+                expected_count: 3,
+                actual_count: 2,
+            })
+        );
+        assert_eq!(
+            winning_holdings(&villain, &Card::vec_from_str("7h8h9hKs").unwrap()),
+            Err(EvaluatorError::FailedToCalculateRank(
+                "Found duplicate cards".to_string()
+            ))
+        );
+    }
+}
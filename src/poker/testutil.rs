@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use rand::Rng;
+use rand_core::RngCore;
+
+use crate::core::{Card, CardDeck, Value};
+use crate::poker::GameKind;
+
+/// Generates a random valid input for a game's evaluator
+///
+/// Returns a tuple of the player's hand and the board, which can be passed directly into
+/// `GameKind::evaluate()`. The hand always contains `kind.required_hole_cards()` cards, except for
+/// Razz where it contains between 5 and 7 cards. Flop games are given a board of 3 to 5 cards,
+/// while all other games are given an empty board. No cards are duplicated across the hand and
+/// the board, and short deck inputs only contain cards ranked 6 or higher.
+///
+/// This is intended for fuzzing evaluators with inputs that should always evaluate successfully.
+///
+/// ## Example
+/// ```rust
+/// use rand_core::SeedableRng;
+/// use rand_xoshiro::Xoshiro256PlusPlus;
+/// use playing_cards::poker::{testutil, GameKind};
+///
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(0);
+///
+/// let (hole, board) = testutil::random_input(GameKind::OmahaHi, &mut rng);
+///
+/// assert_eq!(hole.len(), 4);
+/// assert!(GameKind::OmahaHi.evaluate(&hole, &board).is_ok());
+/// ```
+pub fn random_input(kind: GameKind, rng: &mut dyn RngCore) -> (Vec<Card>, Vec<Card>) {
+    let mut deck = CardDeck::default();
+    if kind == GameKind::ShortDeck {
+        deck.strip_ranks(&HashSet::from([
+            Value::Two,
+            Value::Three,
+            Value::Four,
+            Value::Five,
+        ]));
+    }
+
+    let hole_count = match kind {
+        GameKind::Razz => rng.gen_range(5..=kind.required_hole_cards()),
+        _ => kind.required_hole_cards(),
+    };
+    let board_count = match kind {
+        GameKind::Holdem | GameKind::ShortDeck | GameKind::OmahaHi | GameKind::OmahaHiLo => {
+            rng.gen_range(3..=5)
+        }
+        GameKind::TwoSevenLowball | GameKind::Badugi | GameKind::Razz => 0,
+    };
+
+    // both counts never exceed the size of a short deck
+    let hole = deck.take_random(hole_count, rng).unwrap();
+    let board = deck.take_random(board_count, rng).unwrap();
+
+    (hole, board)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::SeedableRng;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn random_inputs_always_evaluate() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(2507);
+
+        for kind in GameKind::iter() {
+            for _ in 0..1000 {
+                let (hole, board) = random_input(kind, &mut rng);
+
+                let all_cards: HashSet<&Card> = hole.iter().chain(board.iter()).collect();
+                assert_eq!(all_cards.len(), hole.len() + board.len());
+                if let Err(err) = kind.evaluate(&hole, &board) {
+                    panic!(
+                        "{:?} failed to evaluate {:?} with board {:?}: {}",
+                        kind, hole, board, err
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn short_deck_inputs_stay_within_short_deck() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(2507);
+
+        for _ in 0..100 {
+            let (hole, board) = random_input(GameKind::ShortDeck, &mut rng);
+
+            assert!(hole
+                .iter()
+                .chain(board.iter())
+                .all(|card| card.value >= Value::Six));
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//! Re-exports the most commonly used items of the library
+//!
+//! This is intended to be glob imported so that cards, decks, and evaluators are all available
+//! without importing from each module individually. Items from the `poker` module are only
+//! re-exported when the `poker` feature is enabled.
+//!
+//! ```rust
+//! # #[cfg(feature = "poker")]
+//! # {
+//! use playing_cards::prelude::*;
+//!
+//! let hand = Card::vec_from_str("5h5s").unwrap();
+//! let board = Card::vec_from_str("2dTdKs5dAc").unwrap();
+//!
+//! let rank: HighRank = evaluate_high(&[hand, board].concat()).unwrap();
+//!
+//! assert_eq!(rank.description.as_ref().unwrap(), "Trip 5s");
+//! # }
+//! ```
+
+pub use crate::core::{Card, CardDeck, Suit, Value};
+
+#[cfg(feature = "poker")]
+pub use crate::poker::evaluators::{
+    evaluate_badugi, evaluate_dramaha_high, evaluate_high, evaluate_low_27, evaluate_low_a5,
+    evaluate_omaha_hi, evaluate_omaha_hilo, evaluate_short_deck, EvaluatorError,
+};
+
+#[cfg(feature = "poker")]
+pub use crate::poker::ranks::{
+    generate_winner_list, BadugiRank, DramahaHighRank, HighRank, Low27Rank, LowA5Rank, LowRank,
+    OmahaHiLoRank, RazzRank, ShortDeckRank,
+};